@@ -0,0 +1,272 @@
+use std::ops::RangeBounds;
+
+use crate::{
+    AnyParser, BetweenParser, ChainLeftParser, ChainRightParser, ContextParserT, CountParser,
+    ExactParser, ExpectParser, ForgetParser, InputT, LeftRecursiveParser, ManyParser,
+    ManyTillParser, MapParser, MemoParser, NotFollowedByParser, OptionalParser, ParserRc,
+    ParserSyncT, PeekParser, Pos, RepeatParser, SepBy1Parser, SepByParser, SequenceParser, Success,
+    SurelyParser, Trailing,
+};
+
+/// Extension trait offering fluent combinator methods on every `ContextParserT<T>`. <br>
+/// Every method consumes the parser, wraps it in a `ParserRc` and returns the matching parser
+/// struct, so grammars can be written left-to-right instead of as nested constructors.
+/// ## Example
+/// ```
+/// use microparsec::{CombinatorT, IntegerParser, StringParser, SpacesParser, ContextParserT, StringParserT};
+///
+/// let number_parser = IntegerParser::new()
+///     .map(|res| Ok(res.val.parse::<u32>().unwrap()))
+///     .between(StringParser::new("("), StringParser::new(")"));
+///
+/// let res = number_parser.parse("(42)");
+/// assert_eq!(res.unwrap().val, 42);
+///
+/// let greeting_parser = StringParser::new("Hello")
+///     .or(StringParser::new("Hallo"))
+///     .then(SpacesParser::new());
+///
+/// let res = greeting_parser.parse("Hallo Welt");
+/// assert_eq!(res.unwrap().val, vec!["Hallo".to_string(), " ".to_string()]);
+/// ```
+pub trait CombinatorT<T, I: ?Sized = str>: ContextParserT<T, I> {
+    /// Maps the result of the parser to a new value, see `MapParser`
+    fn map<M, F>(self, mapper: F) -> MapParser<T, M, I>
+    where
+        Self: Sized + 'static,
+        F: Fn(Success<T, I>) -> Result<M, String> + ParserSyncT + 'static,
+    {
+        MapParser::new(ParserRc::new(self), mapper)
+    }
+
+    /// Makes the parser optional, see `OptionalParser`
+    fn optional(self) -> OptionalParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        OptionalParser::new(ParserRc::new(self))
+    }
+
+    /// Repeats the parser as many times as possible, see `ManyParser`
+    fn many(self) -> ManyParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        ManyParser::new(ParserRc::new(self))
+    }
+
+    /// Repeats the parser as many times as possible, succeeds on zero matches, see `RepeatParser`
+    fn many0(self) -> RepeatParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        RepeatParser::new(ParserRc::new(self), 0..)
+    }
+
+    /// Repeats the parser within the bounds of a range, see `RepeatParser`
+    fn repeat<R: RangeBounds<usize>>(self, range: R) -> RepeatParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        RepeatParser::new(ParserRc::new(self), range)
+    }
+
+    /// Repeats the parser exactly `count` times, see `CountParser`
+    fn count(self, count: usize) -> CountParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        CountParser::new(ParserRc::new(self), count)
+    }
+
+    /// Repeats the parser until the end parser succeeds, see `ManyTillParser`
+    fn many_till<E, P>(self, end_parser: P) -> ManyTillParser<T, E, I>
+    where
+        Self: Sized + 'static,
+        P: ContextParserT<E, I> + 'static,
+    {
+        ManyTillParser::new(ParserRc::new(self), ParserRc::new(end_parser))
+    }
+
+    /// Forgets the success value of the parser, see `ForgetParser`
+    fn forget(self) -> ForgetParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        ForgetParser::new(ParserRc::new(self))
+    }
+
+    /// Replaces the error message of the parser, see `ExpectParser`
+    fn expect<S: AsRef<str>>(self, expected: S) -> ExpectParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        ExpectParser::new(ParserRc::new(self), expected)
+    }
+
+    /// Marks the parser as required inside of an `AnyParser`, see `SurelyParser`
+    fn surely(self) -> SurelyParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        SurelyParser::new(ParserRc::new(self))
+    }
+
+    /// Surrounds the parser with a front and a back parser, see `BetweenParser`
+    fn between<N, M, A, B>(self, front_parser: A, back_parser: B) -> BetweenParser<N, T, M, I>
+    where
+        Self: Sized + 'static,
+        A: ContextParserT<N, I> + 'static,
+        B: ContextParserT<M, I> + 'static,
+    {
+        BetweenParser::new(
+            ParserRc::new(front_parser),
+            ParserRc::new(self),
+            ParserRc::new(back_parser),
+        )
+    }
+
+    /// Requires the parser to parse a specific amount of characters, see `ExactParser`
+    fn exact(self, pos: Pos) -> ExactParser<T, I>
+    where
+        Self: Sized + 'static,
+        I: InputT,
+    {
+        ExactParser::new(ParserRc::new(self), pos)
+    }
+
+    /// Tries the other parser if this one fails, see `AnyParser`
+    fn or<P>(self, other: P) -> AnyParser<T, I>
+    where
+        Self: Sized + 'static,
+        P: ContextParserT<T, I> + 'static,
+    {
+        AnyParser::new(vec![ParserRc::new(self), ParserRc::new(other)])
+    }
+
+    /// Runs the other parser after this one, see `SequenceParser`
+    fn then<P>(self, other: P) -> SequenceParser<T, I>
+    where
+        Self: Sized + 'static,
+        P: ContextParserT<T, I> + 'static,
+    {
+        SequenceParser::new(vec![ParserRc::new(self), ParserRc::new(other)])
+    }
+
+    /// Parses zero or more occurrences separated by the separator, see `SepByParser`
+    fn sep_by<S, P>(self, separator: P) -> SepByParser<T, S, I>
+    where
+        Self: Sized + 'static,
+        P: ContextParserT<S, I> + 'static,
+    {
+        SepByParser::new(
+            ParserRc::new(self),
+            ParserRc::new(separator),
+            Trailing::Forbidden,
+        )
+    }
+
+    /// Parses one or more occurrences separated by the separator, see `SepBy1Parser`
+    fn sep_by1<S, P>(self, separator: P) -> SepBy1Parser<T, S, I>
+    where
+        Self: Sized + 'static,
+        P: ContextParserT<S, I> + 'static,
+    {
+        SepBy1Parser::new(
+            ParserRc::new(self),
+            ParserRc::new(separator),
+            Trailing::Forbidden,
+        )
+    }
+
+    /// Parses zero or more occurrences separated and optionally ended by the separator, see
+    /// `SepByParser`
+    fn sep_end_by<S, P>(self, separator: P) -> SepByParser<T, S, I>
+    where
+        Self: Sized + 'static,
+        P: ContextParserT<S, I> + 'static,
+    {
+        SepByParser::new(
+            ParserRc::new(self),
+            ParserRc::new(separator),
+            Trailing::Optional,
+        )
+    }
+
+    /// Parses zero or more occurrences each ended by the separator, see `SepByParser`
+    fn end_by<S, P>(self, separator: P) -> SepByParser<T, S, I>
+    where
+        Self: Sized + 'static,
+        P: ContextParserT<S, I> + 'static,
+    {
+        SepByParser::new(
+            ParserRc::new(self),
+            ParserRc::new(separator),
+            Trailing::Required,
+        )
+    }
+
+    /// Folds operands separated by operators left-associatively, see `ChainLeftParser`
+    fn chain_left<F, P>(self, op_parser: P) -> ChainLeftParser<T, F, I>
+    where
+        Self: Sized + 'static,
+        F: Fn(T, T) -> T,
+        P: ContextParserT<F, I> + 'static,
+    {
+        ChainLeftParser::new(ParserRc::new(self), ParserRc::new(op_parser))
+    }
+
+    /// Folds operands separated by operators right-associatively, see `ChainRightParser`
+    fn chain_right<F, P>(self, op_parser: P) -> ChainRightParser<T, F, I>
+    where
+        Self: Sized + 'static,
+        F: Fn(T, T) -> T,
+        P: ContextParserT<F, I> + 'static,
+    {
+        ChainRightParser::new(ParserRc::new(self), ParserRc::new(op_parser))
+    }
+
+    /// Caches the results of the parser by position, see `MemoParser`
+    fn memo(self) -> MemoParser<T, I>
+    where
+        Self: Sized + 'static,
+        T: Clone,
+    {
+        MemoParser::new(ParserRc::new(self))
+    }
+
+    /// Enables left recursion of the parser by growing a seed, see `LeftRecursiveParser`
+    fn left_recursive(self) -> LeftRecursiveParser<T, I>
+    where
+        Self: Sized + 'static,
+        T: Clone,
+    {
+        LeftRecursiveParser::new(ParserRc::new(self))
+    }
+
+    /// Runs the parser without advancing the context, see `PeekParser`
+    fn peek(self) -> PeekParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        PeekParser::new(ParserRc::new(self))
+    }
+
+    /// Succeeds only if the parser fails, without advancing the context, see `NotFollowedByParser`
+    fn not_followed_by(self) -> NotFollowedByParser<T, I>
+    where
+        Self: Sized + 'static,
+    {
+        NotFollowedByParser::new(ParserRc::new(self))
+    }
+
+    /// Wraps the parser in a `ParserRc` to be shared between multiple parsers
+    fn rc(self) -> ParserRc<dyn ContextParserT<T, I>>
+    where
+        Self: Sized + 'static,
+    {
+        ParserRc::new(self)
+    }
+}
+
+impl<T, I: ?Sized, P: ContextParserT<T, I>> CombinatorT<T, I> for P {}
@@ -0,0 +1,131 @@
+use std::ops::Not;
+
+use crate::Failure;
+
+/// Output style of `Failure::get_error_diagnostic`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiagnosticStyle {
+    /// Plain text, suited for logs
+    Plain,
+    /// Colored with ANSI escape codes, suited for terminals
+    Ansi,
+}
+
+impl DiagnosticStyle {
+    fn paint(&self, code: &str, txt: &str) -> String {
+        match self {
+            DiagnosticStyle::Plain => txt.to_string(),
+            DiagnosticStyle::Ansi => format!("\x1b[{code}m{txt}\x1b[0m"),
+        }
+    }
+}
+
+const RED: &str = "1;31";
+const BLUE: &str = "1;34";
+const BOLD: &str = "1";
+const TAB: &str = "    ";
+
+impl Failure {
+    /// Returns a diagnostic of the failure in the style of rustc, showing the offending source line
+    /// with a caret under the failure position, what was expected and the parser call stack
+    /// ## Example
+    /// ```
+    /// use microparsec::{DiagnosticStyle, ParserRc, SequenceParser, StringParser, StringParserT, ContextParserT, parsers};
+    ///
+    /// let parser = SequenceParser::new(parsers!(StringParser::new("Hello\n"), StringParser::new("World")));
+    /// let res = parser.parse("Hello\nWelt");
+    /// assert_eq!(
+    ///     res.unwrap_err().get_error_diagnostic(DiagnosticStyle::Plain),
+    ///     [
+    ///         "error: expected `World`",
+    ///         " --> 2:1",
+    ///         "  |",
+    ///         "2 | Welt",
+    ///         "  | ^",
+    ///         "  |",
+    ///         "  = expected: `World`",
+    ///         "  = call stack:",
+    ///         "      2. `string` parser",
+    ///         "      1. `sequence` parser",
+    ///     ]
+    ///     .join("\n")
+    /// );
+    /// ```
+    pub fn get_error_diagnostic(&self, style: DiagnosticStyle) -> String {
+        self.get_error_diagnostic_from(self.ctx.pos, style)
+    }
+
+    /// Returns a diagnostic of the failure like `get_error_diagnostic`, the input consumed from
+    /// `start` up to the failure position is underlined in front of the caret. Only the part on the
+    /// line of the failure is underlined.
+    /// ## Example
+    /// ```
+    /// use microparsec::{DiagnosticStyle, ParserRc, SequenceParser, StringParser, StringParserT, ContextParserT, parsers};
+    ///
+    /// let parser = SequenceParser::new(parsers!(StringParser::new("let "), StringParser::new("x"), StringParser::new(";")));
+    /// let res = parser.parse("let x = 1;");
+    /// let diagnostic = res.unwrap_err().get_error_diagnostic_from(0, DiagnosticStyle::Plain);
+    /// assert_eq!(diagnostic.lines().nth(3), Some("1 | let x = 1;"));
+    /// assert_eq!(diagnostic.lines().nth(4), Some("  | -----^"));
+    /// ```
+    pub fn get_error_diagnostic_from(&self, start: usize, style: DiagnosticStyle) -> String {
+        let txt = &self.ctx.txt;
+        let pos = self.ctx.pos.min(txt.len());
+        let (line, col) = self.ctx.line_col();
+
+        // the source line containing the failure position
+        let line_start = txt[..pos].rfind('\n').map_or(0, |i| i + 1);
+        let end = txt[pos..].find('\n').map_or(txt.len(), |i| pos + i);
+        let source = txt[line_start..end].trim_end_matches('\r');
+
+        // the consumed part of the line, positions inside of a char are moved to its start
+        let mut from = start.clamp(line_start, pos);
+        while txt.is_char_boundary(from).not() {
+            from -= 1;
+        }
+
+        // tabs are shown as four spaces like rustc does, so the underline lines up with the source
+        let width = |s: &str| {
+            s.chars()
+                .map(|c| if c == '\t' { TAB.len() } else { 1 })
+                .sum()
+        };
+        let padding = " ".repeat(width(&txt[line_start..from]));
+        let underline = "-".repeat(width(&txt[from..pos]));
+        let source = source.replace('\t', TAB);
+
+        let gutter = " ".repeat(line.to_string().len());
+        let bar = style.paint(BLUE, "|");
+
+        let mut lines = vec![
+            format!(
+                "{}: {}",
+                style.paint(RED, "error"),
+                style.paint(BOLD, &format!("expected {}", self.exp))
+            ),
+            format!("{gutter}{} {line}:{col}", style.paint(BLUE, "-->")),
+            format!("{gutter} {bar}"),
+            format!("{} {bar} {source}", style.paint(BLUE, &line.to_string())),
+            format!(
+                "{gutter} {bar} {padding}{}",
+                style.paint(RED, &format!("{underline}^"))
+            ),
+            format!("{gutter} {bar}"),
+            format!("{gutter} {} expected: {}", style.paint(BLUE, "="), self.exp),
+        ];
+
+        if self.p_type_stack.is_empty().not() {
+            lines.push(format!("{gutter} {} call stack:", style.paint(BLUE, "=")));
+
+            let offset = self.p_type_stack.len();
+            lines.extend(
+                self.p_type_stack
+                    .iter()
+                    .enumerate()
+                    .map(|(i, e)| format!("{gutter}     {}. `{e}` parser", offset - i)),
+            );
+        }
+
+        lines.join("\n")
+    }
+}
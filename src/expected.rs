@@ -0,0 +1,167 @@
+use std::{fmt::Display, ops::Not};
+
+/// A single expectation of a failed parse
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Expected {
+    /// A literal string, e.g. the target of a `StringParser`
+    Literal(String),
+    /// A named token, e.g. `integer`
+    Token(String),
+    /// The end of input
+    EndOfInput,
+    /// A custom label, e.g. of an `ExpectParser`
+    Custom(String),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Literal(literal) => write!(f, "{literal}"),
+            Expected::Token(token) => write!(f, "{token}"),
+            Expected::EndOfInput => write!(f, "end of input"),
+            Expected::Custom(label) => write!(f, "{label}"),
+        }
+    }
+}
+
+impl From<&str> for Expected {
+    fn from(label: &str) -> Self {
+        Expected::Custom(label.to_string())
+    }
+}
+
+impl From<&String> for Expected {
+    fn from(label: &String) -> Self {
+        Expected::Custom(label.clone())
+    }
+}
+
+impl From<String> for Expected {
+    fn from(label: String) -> Self {
+        Expected::Custom(label)
+    }
+}
+
+/// Deduplicated collection of expectations, keeps the order of insertion
+/// ## Example
+/// ```
+/// use microparsec::{Expected, ExpectedSet};
+///
+/// let mut expected = ExpectedSet::from(Expected::Literal("b".to_string()));
+/// expected.insert(Expected::Token("a".to_string()));
+/// expected.insert(Expected::Literal("b".to_string()));
+/// expected.insert(Expected::EndOfInput);
+/// assert_eq!(expected.len(), 3);
+/// assert_eq!(expected.to_string(), "`b`, `a` or `end of input`");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpectedSet {
+    expected: Vec<Expected>,
+}
+
+impl ExpectedSet {
+    /// Creates a new empty `ExpectedSet`
+    pub fn new() -> Self {
+        ExpectedSet {
+            expected: Vec::new(),
+        }
+    }
+
+    /// Inserts an expectation if it is not already contained
+    pub fn insert(&mut self, expected: Expected) {
+        if self.expected.contains(&expected).not() {
+            self.expected.push(expected);
+        }
+    }
+
+    /// Inserts all expectations of another `ExpectedSet`
+    pub fn merge(&mut self, other: ExpectedSet) {
+        other.expected.into_iter().for_each(|e| self.insert(e));
+    }
+
+    /// Sorts the expectations
+    pub fn sort(&mut self) {
+        self.expected.sort();
+    }
+
+    /// Returns an iterator over the expectations
+    pub fn iter(&self) -> std::slice::Iter<'_, Expected> {
+        self.expected.iter()
+    }
+
+    /// Returns the number of expectations
+    pub fn len(&self) -> usize {
+        self.expected.len()
+    }
+
+    /// Returns `true` if there are no expectations
+    pub fn is_empty(&self) -> bool {
+        self.expected.is_empty()
+    }
+}
+
+impl ExpectedSet {
+    /// Lists the expectations as `a, b or c`, each surrounded by `quote`
+    pub(crate) fn join(&self, quote: &str) -> String {
+        let expected = self
+            .expected
+            .iter()
+            .map(|e| format!("{quote}{e}{quote}"))
+            .collect::<Vec<String>>();
+
+        match expected.split_last() {
+            Some((last, [])) => last.to_string(),
+            Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+            None => "nothing".to_string(),
+        }
+    }
+}
+
+impl Display for ExpectedSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.join("`"))
+    }
+}
+
+impl From<Expected> for ExpectedSet {
+    fn from(expected: Expected) -> Self {
+        ExpectedSet {
+            expected: vec![expected],
+        }
+    }
+}
+
+impl From<&str> for ExpectedSet {
+    fn from(label: &str) -> Self {
+        ExpectedSet::from(Expected::from(label))
+    }
+}
+
+impl From<&String> for ExpectedSet {
+    fn from(label: &String) -> Self {
+        ExpectedSet::from(Expected::from(label))
+    }
+}
+
+impl From<String> for ExpectedSet {
+    fn from(label: String) -> Self {
+        ExpectedSet::from(Expected::from(label))
+    }
+}
+
+impl FromIterator<Expected> for ExpectedSet {
+    fn from_iter<I: IntoIterator<Item = Expected>>(iter: I) -> Self {
+        let mut expected = ExpectedSet::new();
+        iter.into_iter().for_each(|e| expected.insert(e));
+        expected
+    }
+}
+
+impl IntoIterator for ExpectedSet {
+    type Item = Expected;
+    type IntoIter = std::vec::IntoIter<Expected>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.expected.into_iter()
+    }
+}
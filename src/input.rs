@@ -0,0 +1,104 @@
+use std::{fmt::Debug, ops::Range};
+
+use crate::{LineIndex, ParserRc};
+
+/// Trait for inputs a `Context` can hold, it describes positions in the input for error messages.
+/// It is implemented for strings, whose positions are described as `line:col`, for byte slices,
+/// whose positions are described by their offset, and for slices of `TokenT` tokens, whose
+/// positions are described by their index and source span.
+pub trait InputT {
+    /// Returns the length of the input, the end of input is reached at this position
+    fn input_len(&self) -> usize;
+
+    /// Returns a human readable description of a position in the input
+    fn location(input: &ParserRc<Self>, pos: usize) -> String;
+}
+
+impl InputT for str {
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+
+    fn location(input: &ParserRc<Self>, pos: usize) -> String {
+        let (line, col) = LineIndex::of(input).line_col(input, pos);
+        format!("{line}:{col}")
+    }
+}
+
+impl InputT for [u8] {
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+
+    fn location(_: &ParserRc<Self>, pos: usize) -> String {
+        format!("byte {pos}")
+    }
+}
+
+/// Trait for tokens of a token stream, e.g. the output of a separate lexer, that can be parsed as a
+/// slice of tokens. `TokenParser::new` matches tokens by their kind, tokens may provide their span
+/// in the source text, which is then reported in error messages.
+/// ## Example
+/// ```
+/// use std::ops::Range;
+/// use microparsec::{CombinatorT, TokenParser, TokenT, ContextParserT, SliceParserT};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Kind {
+///     Ident,
+///     Colon,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Token {
+///     kind: Kind,
+///     span: Range<usize>,
+/// }
+///
+/// impl TokenT for Token {
+///     type Kind = Kind;
+///
+///     fn kind(&self) -> Kind {
+///         self.kind.clone()
+///     }
+///
+///     fn span(&self) -> Option<Range<usize>> {
+///         Some(self.span.clone())
+///     }
+/// }
+///
+/// let tokens = [
+///     Token { kind: Kind::Ident, span: 0..3 },
+///     Token { kind: Kind::Ident, span: 4..7 },
+/// ];
+/// let res = TokenParser::new(Kind::Ident).then(TokenParser::new(Kind::Colon)).parse(&tokens);
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `Colon` at token 1 (4..7)"
+/// );
+/// ```
+pub trait TokenT: Clone + PartialEq + Debug {
+    /// Kind of the token without its payload or span, tokens without either can use themselves
+    type Kind: PartialEq + Debug;
+
+    /// Returns the kind of the token
+    fn kind(&self) -> Self::Kind;
+
+    /// Returns the span of the token in the source text, if it is known
+    fn span(&self) -> Option<Range<usize>> {
+        None
+    }
+}
+
+impl<T: TokenT> InputT for [T] {
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+
+    fn location(input: &ParserRc<Self>, pos: usize) -> String {
+        match input.get(pos).and_then(|token| token.span()) {
+            Some(span) => format!("token {pos} ({}..{})", span.start, span.end),
+            None => format!("token {pos}"),
+        }
+    }
+}
@@ -3,9 +3,27 @@
 mod types;
 pub use types::*;
 
+mod input;
+pub use input::*;
+
+mod span;
+pub use span::*;
+
+mod expected;
+pub use expected::*;
+
+mod position;
+pub use position::*;
+
+mod diagnostic;
+pub use diagnostic::*;
+
 mod parsers;
 pub use parsers::*;
 
+mod combinators;
+pub use combinators::*;
+
 #[macro_export]
 macro_rules! parsers {
     ($p:ident) => {
@@ -15,3 +33,10 @@ macro_rules! parsers {
         vec![$(ParserRc::new($p)),*]
     };
 }
+
+#[macro_export]
+macro_rules! tuple_parsers {
+    ($($p:expr),+ $(,)?) => {
+        ($($crate::CombinatorT::rc($p),)+)
+    };
+}
@@ -1,8 +1,11 @@
 mod string;
 pub use string::StringParser;
 
+mod string_literal;
+pub use string_literal::StringLiteralParser;
+
 mod regex;
-pub use self::regex::RegexParser;
+pub use self::regex::{RegexError, RegexParser};
 
 mod optional;
 pub use optional::OptionalParser;
@@ -10,6 +13,9 @@ pub use optional::OptionalParser;
 mod sequence;
 pub use sequence::SequenceParser;
 
+mod tuple;
+pub use tuple::{TupleParser, TupleParsersT};
+
 mod any;
 pub use any::AnyParser;
 
@@ -20,8 +26,34 @@ mod forget;
 pub use forget::ForgetParser;
 
 mod many;
+pub(crate) use many::non_consuming_failure;
 pub use many::ManyParser;
 
+mod sep_by;
+pub use sep_by::{SepBy1Parser, SepByParser};
+
+mod repeat;
+pub(crate) use repeat::repetition;
+pub use repeat::RepeatParser;
+
+mod count;
+pub use count::CountParser;
+
+mod many_till;
+pub use many_till::ManyTillParser;
+
+mod expression;
+pub use expression::{ExpressionParser, Fixity, Operation, Operator};
+
+mod chain;
+pub use chain::{ChainLeftParser, ChainRightParser};
+
+mod memo;
+pub use memo::{MemoParser, MemoStats};
+
+mod left_recursive;
+pub use left_recursive::LeftRecursiveParser;
+
 mod between;
 pub use between::BetweenParser;
 
@@ -37,8 +69,11 @@ pub use letters::LettersParser;
 mod integer;
 pub use integer::IntegerParser;
 
+mod int;
+pub use int::{IntParser, IntT};
+
 mod float;
-pub use float::FloatParser;
+pub use float::{FloatParser, FloatT};
 
 mod expect;
 pub use expect::ExpectParser;
@@ -46,5 +81,33 @@ pub use expect::ExpectParser;
 mod surely;
 pub use surely::SurelyParser;
 
+mod satisfy;
+pub(crate) use satisfy::CharPredicateT;
+pub use satisfy::{NoneOfParser, OneOfParser, SatisfyParser};
+
+mod take_while;
+pub use take_while::TakeWhileParser;
+
 mod not;
 pub use not::NotParser;
+
+mod peek;
+pub use peek::{NotFollowedByParser, PeekParser};
+
+mod recursive;
+pub use recursive::{LazyParser, RecursiveParser};
+
+mod bytes;
+pub use bytes::{ByteParser, BytesParser};
+
+mod binary;
+pub use binary::{BinaryParser, FromBytesT};
+
+mod leb128;
+pub use leb128::{Leb128Parser, Leb128T};
+
+mod length_prefixed;
+pub use length_prefixed::LengthPrefixedParser;
+
+mod token;
+pub use token::TokenParser;
@@ -1,50 +1,72 @@
-use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT, Success};
+use std::ops::Not;
+
+use crate::{
+    Context, ContextParserT, Expected, ExpectedSet, Failure, ParserRc, ParserType, StringParserT,
+    Success,
+};
 
 /// Parses for any of the supplied parsers and returns the first successful result,
-/// or an error if no parser matched.
+/// or an error if no parser matched. <br>
+/// The error is the failure that progressed furthest into the input, if multiple parsers failed
+/// at the furthest position their expectations are merged.
 /// ### Example
 /// ```
-/// use microparsec::{ParserRc, AnyParser, StringParser, ContextParserT, StringParserT, parsers};
+/// use microparsec::{ParserRc, AnyParser, SequenceParser, StringParser, ContextParserT, StringParserT, parsers};
 ///
 /// let hello_parser = StringParser::new("Hello");
 /// let hallo_parser = StringParser::new("Hallo");
-/// let res = AnyParser::new(parsers!(hallo_parser, hello_parser)).parse("Hello World");
+/// let res = AnyParser::new(parsers!(hallo_parser.clone(), hello_parser.clone())).parse("Hello World");
 /// assert_eq!(res.unwrap().val, "Hello");
+///
+/// let hello_world_parser = SequenceParser::new(parsers!(hello_parser, StringParser::new(" World")));
+/// let hallo_welt_parser = SequenceParser::new(parsers!(hallo_parser.clone(), StringParser::new(" Welt")));
+/// let res = AnyParser::new(parsers!(hallo_welt_parser, hello_world_parser)).parse("Hello Welt");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected ` World` at 1:6");
+///
+/// let res = AnyParser::new(parsers!(hallo_parser, StringParser::new("Hi"))).parse("Hello World");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `Hallo` or `Hi` at 1:1");
+///
+/// let parser = AnyParser::new(parsers!(StringParser::new("Hallo"), StringParser::new("Hi")));
+/// assert_eq!(parser.get_generic_error_message(), "Hallo or Hi");
 /// ```
-#[derive(Clone)]
-pub struct AnyParser<T> {
-    parsers: Vec<ParserRc<dyn ContextParserT<T>>>,
-    generic_error: String,
+pub struct AnyParser<T, I: ?Sized = str> {
+    parsers: Vec<ParserRc<dyn ContextParserT<T, I>>>,
+    expected: ExpectedSet,
 }
 
-impl<T> AnyParser<T> {
-    pub fn new(parsers: Vec<ParserRc<dyn ContextParserT<T>>>) -> Self {
-        let generic_error = format!(
-            "{{ `{}` }}",
-            parsers
-                .iter()
-                .map(|p| p.get_generic_error_message().to_string())
-                .collect::<Vec<String>>()
-                .join("` | `")
-        );
+impl<T, I: ?Sized> AnyParser<T, I> {
+    pub fn new(parsers: Vec<ParserRc<dyn ContextParserT<T, I>>>) -> Self {
+        let mut expected = ExpectedSet::new();
+        parsers
+            .iter()
+            .for_each(|p| expected.insert(Expected::Custom(p.get_generic_error_message())));
+
+        AnyParser { parsers, expected }
+    }
+}
 
+impl<T, I: ?Sized> Clone for AnyParser<T, I> {
+    fn clone(&self) -> Self {
         AnyParser {
-            parsers,
-            generic_error,
+            parsers: self.parsers.clone(),
+            expected: self.expected.clone(),
         }
     }
 }
 
-impl<T> ContextParserT<T> for AnyParser<T> {
+impl<T, I: ?Sized> ContextParserT<T, I> for AnyParser<T, I> {
     fn get_generic_error_message(&self) -> String {
-        self.generic_error.clone()
+        self.expected.join("")
     }
 
     fn get_parser_type(&self) -> ParserType {
         ParserType::Any
     }
 
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<T>, Failure> {
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
+        let mut furthest: Option<Failure<I>> = None;
+        let mut merged = false;
+
         for parser in self.parsers.iter() {
             match parser.parse_from_context(ctx.clone()) {
                 Ok(res) => return Ok(res),
@@ -53,15 +75,36 @@ impl<T> ContextParserT<T> for AnyParser<T> {
                         err.p_type_stack.push(ParserType::Any);
                         return Err(err);
                     }
+
+                    // failures without expectations never replace or extend an actual one
+                    match furthest.as_mut() {
+                        Some(_) if err.exp.is_empty() => {}
+                        Some(f) if f.exp.is_empty().not() && f.ctx.pos > err.ctx.pos => {}
+                        Some(f) if f.exp.is_empty().not() && f.ctx.pos == err.ctx.pos => {
+                            f.exp.merge(err.exp);
+                            merged = true;
+                        }
+                        _ => {
+                            furthest = Some(err);
+                            merged = false;
+                        }
+                    }
                 }
             }
         }
 
-        Err(Failure::new(
-            self.generic_error.clone(),
-            ctx,
-            vec![ParserType::Any],
-        ))
+        match furthest {
+            Some(err) if merged => Err(Failure::new(err.exp, err.ctx, vec![ParserType::Any])),
+            Some(mut err) => {
+                err.p_type_stack.push(ParserType::Any);
+                Err(err)
+            }
+            None => Err(Failure::new(
+                self.expected.clone(),
+                ctx,
+                vec![ParserType::Any],
+            )),
+        }
     }
 }
 
@@ -13,19 +13,18 @@ use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParser
 ///             .parse("\"Hello\"");
 /// assert_eq!(res.unwrap().val, "Hello");
 /// ```
-#[derive(Clone)]
-pub struct BetweenParser<N, T, M> {
-    front_parser: ParserRc<dyn ContextParserT<N>>,
-    middle_parser: ParserRc<dyn ContextParserT<T>>,
-    back_parser: ParserRc<dyn ContextParserT<M>>,
+pub struct BetweenParser<N, T, M, I: ?Sized = str> {
+    front_parser: ParserRc<dyn ContextParserT<N, I>>,
+    middle_parser: ParserRc<dyn ContextParserT<T, I>>,
+    back_parser: ParserRc<dyn ContextParserT<M, I>>,
     generic_error: String,
 }
 
-impl<N, T, M> BetweenParser<N, T, M> {
+impl<N, T, M, I: ?Sized> BetweenParser<N, T, M, I> {
     pub fn new(
-        front_parser: ParserRc<dyn ContextParserT<N>>,
-        middle_parser: ParserRc<dyn ContextParserT<T>>,
-        back_parser: ParserRc<dyn ContextParserT<M>>,
+        front_parser: ParserRc<dyn ContextParserT<N, I>>,
+        middle_parser: ParserRc<dyn ContextParserT<T, I>>,
+        back_parser: ParserRc<dyn ContextParserT<M, I>>,
     ) -> Self {
         let generic_error = format!(
             "{} + {} + {}",
@@ -43,7 +42,18 @@ impl<N, T, M> BetweenParser<N, T, M> {
     }
 }
 
-impl<N, T, M> ContextParserT<T> for BetweenParser<N, T, M> {
+impl<N, T, M, I: ?Sized> Clone for BetweenParser<N, T, M, I> {
+    fn clone(&self) -> Self {
+        BetweenParser {
+            front_parser: self.front_parser.clone(),
+            middle_parser: self.middle_parser.clone(),
+            back_parser: self.back_parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<N, T, M, I: ?Sized> ContextParserT<T, I> for BetweenParser<N, T, M, I> {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -52,7 +62,7 @@ impl<N, T, M> ContextParserT<T> for BetweenParser<N, T, M> {
         ParserType::Between
     }
 
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<T>, Failure> {
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
         let ctx = match self.front_parser.parse_from_context(ctx) {
             Ok(res) => res.ctx,
             Err(mut err) => {
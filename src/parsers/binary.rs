@@ -0,0 +1,110 @@
+use std::marker::PhantomData;
+
+use crate::{Context, ContextParserT, Endian, Expected, Failure, ParserType, Success};
+
+/// Trait for numbers that can be read from a fixed number of bytes by a `BinaryParser`. It is
+/// implemented for all primitive integer and float types.
+pub trait FromBytesT: Sized {
+    /// Name of the type, used in error messages
+    const NAME: &'static str;
+    /// Number of bytes of the type
+    const SIZE: usize;
+
+    /// Creates the number from exactly `SIZE` bytes in the given byte order
+    fn from_bytes(bytes: &[u8], endian: Endian) -> Self;
+}
+
+macro_rules! from_bytes_impl {
+    ($($t:ty),+) => {
+        $(
+            impl FromBytesT for $t {
+                const NAME: &'static str = stringify!($t);
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn from_bytes(bytes: &[u8], endian: Endian) -> Self {
+                    let bytes = bytes.try_into().expect("Slice must have the size of the type");
+                    match endian {
+                        Endian::Big => <$t>::from_be_bytes(bytes),
+                        Endian::Little => <$t>::from_le_bytes(bytes),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+from_bytes_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// Parses a fixed size number in big or little endian byte order
+/// ## Example
+/// ```
+/// use microparsec::{BinaryParser, Endian, ContextParserT, SliceParserT};
+///
+/// let length_parser = BinaryParser::<u32>::new(Endian::Big);
+/// let res = length_parser.parse(&[0x00, 0x00, 0x01, 0x02, 0xff]);
+/// assert_eq!(res.clone().unwrap().val, 258);
+/// assert_eq!(res.unwrap().ctx.pos, 4);
+///
+/// let res = BinaryParser::<f64>::new(Endian::Little).parse(&1.5_f64.to_le_bytes());
+/// assert_eq!(res.unwrap().val, 1.5);
+///
+/// let res = length_parser.parse(&[0x00, 0x01]);
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `big endian u32` at byte 0"
+/// );
+/// ```
+pub struct BinaryParser<N> {
+    endian: Endian,
+    generic_error: String,
+    number: PhantomData<N>,
+}
+
+impl<N: FromBytesT> BinaryParser<N> {
+    /// Creates a new `BinaryParser` reading the number in the specified byte order
+    pub fn new(endian: Endian) -> Self {
+        BinaryParser {
+            endian,
+            generic_error: format!("{endian} {}", N::NAME),
+            number: PhantomData,
+        }
+    }
+}
+
+impl<N> Clone for BinaryParser<N> {
+    fn clone(&self) -> Self {
+        BinaryParser {
+            endian: self.endian,
+            generic_error: self.generic_error.clone(),
+            number: PhantomData,
+        }
+    }
+}
+
+impl<N: FromBytesT> ContextParserT<N, [u8]> for BinaryParser<N> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Binary
+    }
+
+    fn parse_from_context(
+        &self,
+        mut ctx: Context<[u8]>,
+    ) -> Result<Success<N, [u8]>, Failure<[u8]>> {
+        match ctx.txt.get(ctx.pos..ctx.pos + N::SIZE) {
+            Some(bytes) => {
+                let val = N::from_bytes(bytes, self.endian);
+                ctx.pos += N::SIZE;
+                Ok(Success::new(val, ctx))
+            }
+            None => Err(Failure::new(
+                Expected::Token(self.generic_error.clone()),
+                ctx,
+                vec![ParserType::Binary],
+            )),
+        }
+    }
+}
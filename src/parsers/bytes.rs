@@ -0,0 +1,140 @@
+use std::ops::Not;
+
+use crate::{
+    Context, ContextParserT, Expected, Failure, ParserRc, ParserSyncT, ParserType, Success,
+};
+
+/// Parses for a specific target byte sequence
+/// ## Example
+/// ```
+/// use microparsec::{BytesParser, ContextParserT, SliceParserT};
+///
+/// let magic_parser = BytesParser::new(b"\x89PNG");
+/// let res = magic_parser.parse(b"\x89PNG\r\n");
+/// assert_eq!(res.clone().unwrap().val, b"\x89PNG".to_vec());
+/// assert_eq!(res.unwrap().ctx.pos, 4);
+///
+/// let res = magic_parser.parse(b"GIF89a");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `0x89 0x50 0x4e 0x47` at byte 0"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct BytesParser {
+    target: Vec<u8>,
+    generic_error: String,
+}
+
+impl BytesParser {
+    /// Creates a new `BytesParser` with the specified target bytes
+    pub fn new<B: AsRef<[u8]>>(target: B) -> Self {
+        assert!(target.as_ref().is_empty().not(), "Target must not be empty");
+
+        let generic_error = target
+            .as_ref()
+            .iter()
+            .map(|b| format!("{b:#04x}"))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        BytesParser {
+            target: target.as_ref().to_vec(),
+            generic_error,
+        }
+    }
+}
+
+impl ContextParserT<Vec<u8>, [u8]> for BytesParser {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Bytes
+    }
+
+    fn parse_from_context(
+        &self,
+        mut ctx: Context<[u8]>,
+    ) -> Result<Success<Vec<u8>, [u8]>, Failure<[u8]>> {
+        if ctx.txt[ctx.pos..].starts_with(&self.target) {
+            ctx.pos += self.target.len();
+            return Ok(Success::new(self.target.clone(), ctx));
+        }
+
+        Err(Failure::new(
+            Expected::Literal(self.generic_error.clone()),
+            ctx,
+            vec![ParserType::Bytes],
+        ))
+    }
+}
+
+/// Predicate of a `ByteParser`
+trait BytePredicateT: Fn(u8) -> bool + ParserSyncT {}
+
+impl<F: Fn(u8) -> bool + ParserSyncT> BytePredicateT for F {}
+
+/// Parses a single byte that satisfies a predicate
+/// ## Example
+/// ```
+/// use microparsec::{ByteParser, ContextParserT, SliceParserT};
+///
+/// let version_parser = ByteParser::new(|b| b < 0x80, "version");
+/// let res = version_parser.parse(&[0x02, 0xff]);
+/// assert_eq!(res.clone().unwrap().val, 0x02);
+/// assert_eq!(res.unwrap().ctx.pos, 1);
+///
+/// let res = version_parser.parse(&[0xff]);
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `version` at byte 0"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct ByteParser {
+    predicate: ParserRc<dyn BytePredicateT>,
+    generic_error: String,
+}
+
+impl ByteParser {
+    /// Creates a new `ByteParser`, the name is used as its generic error message
+    pub fn new<F, S>(predicate: F, expected: S) -> Self
+    where
+        F: Fn(u8) -> bool + ParserSyncT + 'static,
+        S: AsRef<str>,
+    {
+        ByteParser {
+            predicate: ParserRc::new(predicate),
+            generic_error: expected.as_ref().to_string(),
+        }
+    }
+}
+
+impl ContextParserT<u8, [u8]> for ByteParser {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Byte
+    }
+
+    fn parse_from_context(
+        &self,
+        mut ctx: Context<[u8]>,
+    ) -> Result<Success<u8, [u8]>, Failure<[u8]>> {
+        match ctx.txt.get(ctx.pos).copied() {
+            Some(byte) if (self.predicate)(byte) => {
+                ctx.pos += 1;
+                Ok(Success::new(byte, ctx))
+            }
+            _ => Err(Failure::new(
+                Expected::Token(self.generic_error.clone()),
+                ctx,
+                vec![ParserType::Byte],
+            )),
+        }
+    }
+}
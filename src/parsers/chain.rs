@@ -0,0 +1,201 @@
+use crate::{
+    non_consuming_failure, Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT,
+    Success,
+};
+
+/// Parses one or more operands separated by operators and folds them left-associatively with the
+/// combining functions returned by the operator parser, e.g. `a - b - c` = `(a - b) - c`
+/// ## Example
+/// ```
+/// use microparsec::{ChainLeftParser, CombinatorT, IntegerParser, StringParser, ParserRc, ContextParserT, StringParserT};
+///
+/// let number_parser = IntegerParser::new().map(|res| Ok(res.val.parse::<i64>().unwrap()));
+/// let minus_parser = StringParser::new("-").map(|_| Ok(|a: i64, b: i64| a - b));
+///
+/// let parser = ChainLeftParser::new(ParserRc::new(number_parser), ParserRc::new(minus_parser));
+/// assert_eq!(parser.parse("10-2-3").unwrap().val, 5);
+/// assert_eq!(
+///     parser.parse("10-").unwrap_err().get_error_message(),
+///     "[Parser error] Expected `integer` at 1:4"
+/// );
+/// ```
+pub struct ChainLeftParser<T, F, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    op_parser: ParserRc<dyn ContextParserT<F, I>>,
+    generic_error: String,
+}
+
+impl<T, F: Fn(T, T) -> T, I: ?Sized> ChainLeftParser<T, F, I> {
+    pub fn new(
+        parser: ParserRc<dyn ContextParserT<T, I>>,
+        op_parser: ParserRc<dyn ContextParserT<F, I>>,
+    ) -> Self {
+        let generic_error = format!(
+            "`{}` chained by `{}`",
+            parser.get_generic_error_message(),
+            op_parser.get_generic_error_message()
+        );
+
+        ChainLeftParser {
+            parser,
+            op_parser,
+            generic_error,
+        }
+    }
+}
+
+impl<T, F, I: ?Sized> Clone for ChainLeftParser<T, F, I> {
+    fn clone(&self) -> Self {
+        ChainLeftParser {
+            parser: self.parser.clone(),
+            op_parser: self.op_parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, F: Fn(T, T) -> T, I: ?Sized> ContextParserT<T, I> for ChainLeftParser<T, F, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::ChainLeft
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
+        let (operands, ops, ctx) =
+            chain(&self.parser, &self.op_parser, ParserType::ChainLeft, ctx)?;
+
+        let mut operands = operands.into_iter();
+        let first = operands.next().expect("chain parses at least one operand");
+        let val = ops
+            .into_iter()
+            .zip(operands)
+            .fold(first, |acc, (op, x)| op(acc, x));
+
+        Ok(Success::new(val, ctx))
+    }
+}
+
+impl<T, F: Fn(T, T) -> T> StringParserT<T> for ChainLeftParser<T, F> {}
+
+/// Parses one or more operands separated by operators and folds them right-associatively with the
+/// combining functions returned by the operator parser, e.g. `a ^ b ^ c` = `a ^ (b ^ c)`
+/// ## Example
+/// ```
+/// use microparsec::{ChainRightParser, CombinatorT, IntegerParser, StringParser, ParserRc, ContextParserT, StringParserT};
+///
+/// let number_parser = IntegerParser::new().map(|res| Ok(res.val.parse::<u32>().unwrap()));
+/// let pow_parser = StringParser::new("^").map(|_| Ok(|a: u32, b: u32| a.pow(b)));
+///
+/// let parser = ChainRightParser::new(ParserRc::new(number_parser), ParserRc::new(pow_parser));
+/// assert_eq!(parser.parse("2^3^2").unwrap().val, 512);
+/// ```
+pub struct ChainRightParser<T, F, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    op_parser: ParserRc<dyn ContextParserT<F, I>>,
+    generic_error: String,
+}
+
+impl<T, F: Fn(T, T) -> T, I: ?Sized> ChainRightParser<T, F, I> {
+    pub fn new(
+        parser: ParserRc<dyn ContextParserT<T, I>>,
+        op_parser: ParserRc<dyn ContextParserT<F, I>>,
+    ) -> Self {
+        let generic_error = format!(
+            "`{}` chained by `{}`",
+            parser.get_generic_error_message(),
+            op_parser.get_generic_error_message()
+        );
+
+        ChainRightParser {
+            parser,
+            op_parser,
+            generic_error,
+        }
+    }
+}
+
+impl<T, F, I: ?Sized> Clone for ChainRightParser<T, F, I> {
+    fn clone(&self) -> Self {
+        ChainRightParser {
+            parser: self.parser.clone(),
+            op_parser: self.op_parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, F: Fn(T, T) -> T, I: ?Sized> ContextParserT<T, I> for ChainRightParser<T, F, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::ChainRight
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
+        let (operands, ops, ctx) =
+            chain(&self.parser, &self.op_parser, ParserType::ChainRight, ctx)?;
+
+        let mut operands = operands.into_iter().rev();
+        let last = operands.next().expect("chain parses at least one operand");
+        let val = ops
+            .into_iter()
+            .rev()
+            .zip(operands)
+            .fold(last, |acc, (op, x)| op(x, acc));
+
+        Ok(Success::new(val, ctx))
+    }
+}
+
+impl<T, F: Fn(T, T) -> T> StringParserT<T> for ChainRightParser<T, F> {}
+
+/// Operands and operators of a chain and the context after them
+type Chain<T, F, I> = (Vec<T>, Vec<F>, Context<I>);
+
+/// Shared implementation of `ChainLeftParser` and `ChainRightParser`, returns the operands and the
+/// operators in between them
+fn chain<T, F, I: ?Sized>(
+    parser: &ParserRc<dyn ContextParserT<T, I>>,
+    op_parser: &ParserRc<dyn ContextParserT<F, I>>,
+    p_type: ParserType,
+    ctx: Context<I>,
+) -> Result<Chain<T, F, I>, Failure<I>> {
+    let res = match parser.parse_from_context(ctx) {
+        Ok(res) => res,
+        Err(mut err) => {
+            err.p_type_stack.push(p_type);
+            return Err(err);
+        }
+    };
+
+    let mut operands = vec![res.val];
+    let mut ops = Vec::new();
+    let mut ctx = res.ctx;
+
+    loop {
+        let op = match op_parser.parse_from_context(ctx.clone()) {
+            Ok(op) => op,
+            Err(_) => return Ok((operands, ops, ctx)),
+        };
+
+        match parser.parse_from_context(op.ctx) {
+            Ok(res) if res.ctx.pos == ctx.pos => {
+                return Err(non_consuming_failure(parser, ctx, p_type));
+            }
+            Ok(res) => {
+                ctx = res.ctx;
+                ops.push(op.val);
+                operands.push(res.val);
+            }
+            Err(mut err) => {
+                err.p_type_stack.push(p_type);
+                return Err(err);
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    Context, ContextParserT, ExpectedSet, Failure, ParserRc, ParserType, StringParserT, Success,
+};
+
+/// Parses exactly `count` times, returns an error if less parses were successful
+/// ## Example
+/// ```
+/// use microparsec::{CountParser, ContextParserT, StringParserT, ParserRc, RegexParser};
+///
+/// let digit_parser = ParserRc::new(RegexParser::new(r"\d", "digit"));
+/// let parser = CountParser::new(digit_parser, 3);
+///
+/// let res = parser.parse("1234");
+/// assert_eq!(res.clone().unwrap().val, vec!["1".to_string(), "2".to_string(), "3".to_string()]);
+/// assert_eq!(res.unwrap().ctx.pos, 3);
+///
+/// let res = parser.parse("12");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `exactly 3 `digit` (found 2)` at 1:3"
+/// );
+/// ```
+pub struct CountParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    count: usize,
+    generic_error: String,
+}
+
+impl<T, I: ?Sized> CountParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>, count: usize) -> Self {
+        let generic_error = format!("exactly {count} `{}`", parser.get_generic_error_message());
+
+        CountParser {
+            parser,
+            count,
+            generic_error,
+        }
+    }
+}
+
+impl<T, I: ?Sized> Clone for CountParser<T, I> {
+    fn clone(&self) -> Self {
+        CountParser {
+            parser: self.parser.clone(),
+            count: self.count,
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<Vec<T>, I> for CountParser<T, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Count
+    }
+
+    fn parse_from_context(&self, mut ctx: Context<I>) -> Result<Success<Vec<T>, I>, Failure<I>> {
+        let mut ret = Vec::with_capacity(self.count);
+
+        while ret.len() < self.count {
+            match self.parser.parse_from_context(ctx.clone()) {
+                Ok(res) => {
+                    ctx = res.ctx;
+                    ret.push(res.val);
+                }
+                Err(mut err) => {
+                    err.exp =
+                        ExpectedSet::from(format!("{} (found {})", self.generic_error, ret.len()));
+                    err.p_type_stack.push(ParserType::Count);
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(Success::new(ret, ctx))
+    }
+}
+
+impl<T> StringParserT<Vec<T>> for CountParser<T> {}
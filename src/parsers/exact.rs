@@ -1,4 +1,7 @@
-use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, Pos, StringParserT, Success};
+use crate::{
+    Context, ContextParserT, Expected, Failure, InputT, ParserRc, ParserType, Pos, StringParserT,
+    Success,
+};
 
 /// Attempts to parse a specified number of chars or to the EOI and fails otherwise
 /// ## Example
@@ -6,18 +9,22 @@ use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, Pos, StringP
 /// use microparsec::{ParserRc, ExactParser, StringParser, Pos, ContextParserT, StringParserT};
 ///
 /// let hello_world_parser = StringParser::new("Hello World");
-/// let res = ExactParser::new(ParserRc::new(hello_world_parser), Pos::EOI).parse("Hello World");
+/// let parser = ExactParser::new(ParserRc::new(hello_world_parser), Pos::EOI);
+///
+/// let res = parser.parse("Hello World");
 /// assert_eq!(res.unwrap().val, "Hello World");
+///
+/// let res = parser.parse("Hello World!");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `end of input` at 1:12");
 /// ```
-#[derive(Clone)]
-pub struct ExactParser<T> {
-    parser: ParserRc<dyn ContextParserT<T>>,
+pub struct ExactParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
     pos: Pos,
     generic_error: String,
 }
 
-impl<T> ExactParser<T> {
-    pub fn new(parser: ParserRc<dyn ContextParserT<T>>, pos: Pos) -> Self {
+impl<T, I: ?Sized> ExactParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>, pos: Pos) -> Self {
         let generic_error = format!("exactly `{}`", parser.clone().get_generic_error_message());
         ExactParser {
             parser,
@@ -27,7 +34,17 @@ impl<T> ExactParser<T> {
     }
 }
 
-impl<T> ContextParserT<T> for ExactParser<T> {
+impl<T, I: ?Sized> Clone for ExactParser<T, I> {
+    fn clone(&self) -> Self {
+        ExactParser {
+            parser: self.parser.clone(),
+            pos: self.pos,
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: InputT + ?Sized> ContextParserT<T, I> for ExactParser<T, I> {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -36,7 +53,7 @@ impl<T> ContextParserT<T> for ExactParser<T> {
         ParserType::Exact
     }
 
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<T>, Failure> {
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
         let prev_pos = ctx.pos;
         let mut res = match self.parser.parse_from_context(ctx) {
             Ok(res) => res,
@@ -60,12 +77,12 @@ impl<T> ContextParserT<T> for ExactParser<T> {
                 }
             }
             Pos::EOI => {
-                if res.ctx.pos == res.ctx.txt.len() {
+                if res.ctx.pos == res.ctx.txt.input_len() {
                     Ok(res)
                 } else {
-                    res.ctx.pos = prev_pos;
+                    // the input continues where the end was expected
                     Err(Failure::new(
-                        "parsing to EOI",
+                        Expected::EndOfInput,
                         res.ctx,
                         vec![ParserType::Exact],
                     ))
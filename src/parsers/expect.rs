@@ -1,4 +1,6 @@
-use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT, Success};
+use crate::{
+    Context, ContextParserT, ExpectedSet, Failure, ParserRc, ParserType, StringParserT, Success,
+};
 
 /// Runs a supplied parser, if fails, returns a custom error message
 /// ## Example
@@ -7,16 +9,15 @@ use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParser
 ///
 /// let hello_world_parser = StringParser::new("Hello World");
 /// let res = ExpectParser::new(ParserRc::new(hello_world_parser), "\"Hello World\"").parse("Hallo Welt");
-/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `\"Hello World\"` at position: 0");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `\"Hello World\"` at 1:1");
 /// ```
-#[derive(Clone)]
-pub struct ExpectParser<T> {
-    parser: ParserRc<dyn ContextParserT<T>>,
+pub struct ExpectParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
     generic_error: String,
 }
 
-impl<T> ExpectParser<T> {
-    pub fn new<S: AsRef<str>>(parser: ParserRc<dyn ContextParserT<T>>, expected: S) -> Self {
+impl<T, I: ?Sized> ExpectParser<T, I> {
+    pub fn new<S: AsRef<str>>(parser: ParserRc<dyn ContextParserT<T, I>>, expected: S) -> Self {
         let generic_error = expected.as_ref().to_string();
 
         ExpectParser {
@@ -26,7 +27,16 @@ impl<T> ExpectParser<T> {
     }
 }
 
-impl<T> ContextParserT<T> for ExpectParser<T> {
+impl<T, I: ?Sized> Clone for ExpectParser<T, I> {
+    fn clone(&self) -> Self {
+        ExpectParser {
+            parser: self.parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<T, I> for ExpectParser<T, I> {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -35,11 +45,11 @@ impl<T> ContextParserT<T> for ExpectParser<T> {
         ParserType::Expect
     }
 
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<T>, Failure> {
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
         match self.parser.parse_from_context(ctx.clone()) {
             Ok(res) => Ok(res),
             Err(mut err) => {
-                err.exp = self.generic_error.clone();
+                err.exp = ExpectedSet::from(&self.generic_error);
                 err.p_type_stack.push(ParserType::Expect);
                 Err(err)
             }
@@ -0,0 +1,254 @@
+use crate::{
+    Context, ContextParserT, Failure, ParserRc, ParserSyncT, ParserType, StringParserT, Success,
+};
+
+/// Fixity of an operator of an `ExpressionParser`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fixity {
+    /// Unary operator in front of its operand, e.g. `-x`
+    Prefix,
+    /// Left-associative binary operator, e.g. `a - b - c` = `(a - b) - c`
+    InfixLeft,
+    /// Right-associative binary operator, e.g. `a ^ b ^ c` = `a ^ (b ^ c)`
+    InfixRight,
+    /// Unary operator behind its operand, e.g. `x!`
+    Postfix,
+}
+
+/// Operator of an `ExpressionParser`. Operators with a higher binding power bind tighter.
+pub struct Operator<O, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<O, I>>,
+    fixity: Fixity,
+    binding_power: u32,
+}
+
+impl<O, I: ?Sized> Operator<O, I> {
+    /// Creates a new `Operator`
+    /// * `parser` - parser of the operator symbol
+    /// * `fixity` - position and associativity of the operator
+    /// * `binding_power` - precedence of the operator, left-associative operators bind their right
+    ///   operand one higher, so their binding power must be less than `u32::MAX`
+    pub fn new(
+        parser: ParserRc<dyn ContextParserT<O, I>>,
+        fixity: Fixity,
+        binding_power: u32,
+    ) -> Self {
+        assert!(
+            fixity != Fixity::InfixLeft || binding_power < u32::MAX,
+            "Binding power of a left-associative operator must be less than u32::MAX"
+        );
+
+        Operator {
+            parser,
+            fixity,
+            binding_power,
+        }
+    }
+}
+
+impl<O, I: ?Sized> Clone for Operator<O, I> {
+    fn clone(&self) -> Self {
+        Operator {
+            parser: self.parser.clone(),
+            fixity: self.fixity,
+            binding_power: self.binding_power,
+        }
+    }
+}
+
+/// Operation of an operator and its operands that is folded into a single value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation<T, O> {
+    Prefix(O, T),
+    Infix(T, O, T),
+    Postfix(T, O),
+}
+
+/// Operator that was parsed and its parse result
+type ParsedOperator<'a, O, I> = (&'a Operator<O, I>, Success<O, I>);
+
+/// Folding function of an `ExpressionParser`
+trait FoldT<T, O>: Fn(Operation<T, O>) -> T + ParserSyncT {}
+
+impl<T, O, F: Fn(Operation<T, O>) -> T + ParserSyncT> FoldT<T, O> for F {}
+
+/// Parses an expression of atoms and operators by precedence climbing. Every parsed operation is
+/// folded into a single value by the supplied closure.
+/// ## Example
+/// ```
+/// use microparsec::{CombinatorT, ExpressionParser, Fixity, IntegerParser, Operation, Operator, ParserRc, StringParser, ContextParserT, StringParserT};
+///
+/// let number_parser = IntegerParser::new().map(|res| Ok(res.val.parse::<i64>().unwrap()));
+/// let operators = vec![
+///     Operator::new(ParserRc::new(StringParser::new("+")), Fixity::InfixLeft, 1),
+///     Operator::new(ParserRc::new(StringParser::new("-")), Fixity::InfixLeft, 1),
+///     Operator::new(ParserRc::new(StringParser::new("*")), Fixity::InfixLeft, 2),
+///     Operator::new(ParserRc::new(StringParser::new("^")), Fixity::InfixRight, 3),
+///     Operator::new(ParserRc::new(StringParser::new("-")), Fixity::Prefix, 4),
+///     Operator::new(ParserRc::new(StringParser::new("!")), Fixity::Postfix, 5),
+/// ];
+///
+/// let parser = ExpressionParser::new(ParserRc::new(number_parser), operators, |operation| {
+///     match operation {
+///         Operation::Prefix(_, x) => -x,
+///         Operation::Infix(a, op, b) => match op.as_str() {
+///             "+" => a + b,
+///             "-" => a - b,
+///             "*" => a * b,
+///             _ => a.pow(b as u32),
+///         },
+///         Operation::Postfix(x, _) => (1..=x).product(),
+///     }
+/// });
+///
+/// assert_eq!(parser.parse("2*3^2-1").unwrap().val, 17);
+/// assert_eq!(parser.parse("10-2-3").unwrap().val, 5);
+/// assert_eq!(parser.parse("-3!+2").unwrap().val, -4);
+/// assert_eq!(
+///     parser.parse("1+").unwrap_err().get_error_message(),
+///     "[Parser error] Expected `operand after `+`` at 1:3"
+/// );
+/// ```
+pub struct ExpressionParser<T, O, I: ?Sized = str> {
+    atom_parser: ParserRc<dyn ContextParserT<T, I>>,
+    operators: Vec<Operator<O, I>>,
+    fold: ParserRc<dyn FoldT<T, O>>,
+    generic_error: String,
+}
+
+impl<T, O, I: ?Sized> ExpressionParser<T, O, I> {
+    pub fn new<F>(
+        atom_parser: ParserRc<dyn ContextParserT<T, I>>,
+        operators: Vec<Operator<O, I>>,
+        fold: F,
+    ) -> Self
+    where
+        F: Fn(Operation<T, O>) -> T + ParserSyncT + 'static,
+    {
+        let generic_error = format!(
+            "expression of `{}`",
+            atom_parser.get_generic_error_message()
+        );
+
+        ExpressionParser {
+            atom_parser,
+            operators,
+            fold: ParserRc::new(fold),
+            generic_error,
+        }
+    }
+
+    /// Parses the longest operator of the given fixities, it is only used if it has at least the
+    /// given binding power. Operators that don't consume input are ignored.
+    fn parse_operator(
+        &self,
+        fixities: &[Fixity],
+        min_bp: u32,
+        ctx: &Context<I>,
+    ) -> Option<ParsedOperator<'_, O, I>> {
+        let mut longest: Option<ParsedOperator<'_, O, I>> = None;
+        for op in self
+            .operators
+            .iter()
+            .filter(|op| fixities.contains(&op.fixity))
+        {
+            let pos = longest.as_ref().map_or(ctx.pos, |(_, res)| res.ctx.pos);
+            match op.parser.parse_from_context(ctx.clone()) {
+                Ok(res) if res.ctx.pos > pos => longest = Some((op, res)),
+                _ => {}
+            }
+        }
+
+        // a shorter operator is never used in place of a longest one that binds too loosely
+        longest.filter(|(op, _)| op.binding_power >= min_bp)
+    }
+
+    /// Parses the operand of an operator that was parsed right before the context
+    fn parse_operand(
+        &self,
+        ctx: Context<I>,
+        op: &Operator<O, I>,
+        min_bp: u32,
+    ) -> Result<Success<T, I>, Failure<I>> {
+        match self.parse_expression(ctx.clone(), min_bp) {
+            Ok(res) => Ok(res),
+            Err(err) if err.ctx.pos <= ctx.pos => {
+                let operator = op.parser.get_generic_error_message();
+                Err(Failure::new(
+                    format!("operand after `{operator}`"),
+                    ctx,
+                    vec![ParserType::Expression],
+                ))
+            }
+            Err(mut err) => {
+                err.p_type_stack.push(ParserType::Expression);
+                Err(err)
+            }
+        }
+    }
+
+    fn parse_expression(&self, ctx: Context<I>, min_bp: u32) -> Result<Success<T, I>, Failure<I>> {
+        let mut lhs = match self.parse_operator(&[Fixity::Prefix], 0, &ctx) {
+            Some((op, res)) => {
+                let operand = self.parse_operand(res.ctx, op, op.binding_power)?;
+                let val = (self.fold)(Operation::Prefix(res.val, operand.val));
+                Success::new(val, operand.ctx)
+            }
+            None => match self.atom_parser.parse_from_context(ctx) {
+                Ok(res) => res,
+                Err(mut err) => {
+                    err.p_type_stack.push(ParserType::Expression);
+                    return Err(err);
+                }
+            },
+        };
+
+        loop {
+            if let Some((_, res)) = self.parse_operator(&[Fixity::Postfix], min_bp, &lhs.ctx) {
+                lhs = Success::new((self.fold)(Operation::Postfix(lhs.val, res.val)), res.ctx);
+                continue;
+            }
+
+            let infix = [Fixity::InfixLeft, Fixity::InfixRight];
+            let (op, res) = match self.parse_operator(&infix, min_bp, &lhs.ctx) {
+                Some(op) => op,
+                None => return Ok(lhs),
+            };
+
+            let next_bp = match op.fixity {
+                Fixity::InfixLeft => op.binding_power + 1,
+                _ => op.binding_power,
+            };
+            let rhs = self.parse_operand(res.ctx, op, next_bp)?;
+            let val = (self.fold)(Operation::Infix(lhs.val, res.val, rhs.val));
+            lhs = Success::new(val, rhs.ctx);
+        }
+    }
+}
+
+impl<T, O, I: ?Sized> Clone for ExpressionParser<T, O, I> {
+    fn clone(&self) -> Self {
+        ExpressionParser {
+            atom_parser: self.atom_parser.clone(),
+            operators: self.operators.clone(),
+            fold: self.fold.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, O, I: ?Sized> ContextParserT<T, I> for ExpressionParser<T, O, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Expression
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
+        self.parse_expression(ctx, 0)
+    }
+}
+
+impl<T, O> StringParserT<T> for ExpressionParser<T, O> {}
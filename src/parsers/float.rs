@@ -1,24 +1,76 @@
-use crate::{Context, ContextParserT, Failure, ParserType, RegexParser, StringParserT, Success};
+use std::{marker::PhantomData, ops::Not, str::FromStr};
 
-/// Parses for a float
+use crate::{
+    Context, ContextParserT, Expected, Failure, FloatSyntax, ParserType, StringParserT, Success,
+};
+
+/// Trait for floats that can be parsed by a `FloatParser`. It is implemented for `f32` and `f64`.
+pub trait FloatT: FromStr {
+    fn is_finite(&self) -> bool;
+}
+
+impl FloatT for f32 {
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+}
+
+impl FloatT for f64 {
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+/// Parses a float, the accepted syntax is determined by `FloatSyntax`. The matched text is
+/// converted with `str::parse`, so the value is correctly rounded to the nearest float. In `Json` and
+/// `Rust` mode a literal too large for the type fails at its start instead of becoming infinite.
 /// ## Example
 /// ```
-/// use microparsec::{FloatParser, ContextParserT, StringParserT};
+/// use microparsec::{FloatParser, FloatSyntax, ContextParserT, StringParserT};
+///
+/// let res = FloatParser::<f64>::new(FloatSyntax::Json).parse("-1.5E-3,");
+/// assert_eq!(res.clone().unwrap().val, -1.5e-3);
+/// assert_eq!(res.unwrap().ctx.pos, 7);
+///
+/// let res = FloatParser::<f32>::new(FloatSyntax::Rust).parse("1_000.25");
+/// assert_eq!(res.unwrap().val, 1000.25);
+///
+/// let res = FloatParser::<f64>::new(FloatSyntax::Permissive).parse("-inf");
+/// assert_eq!(res.unwrap().val, f64::NEG_INFINITY);
 ///
-/// let res = FloatParser::new().parse("123.456");
-/// assert_eq!(res.unwrap().val, "123.456");
+/// let res = FloatParser::<f64>::new(FloatSyntax::Json).parse(".5");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `float` at 1:1");
+///
+/// let res = FloatParser::<f64>::new(FloatSyntax::Json).parse("1e400");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `float (out of range)` at 1:1"
+/// );
 /// ```
-#[derive(Clone)]
-pub struct FloatParser {}
+pub struct FloatParser<N> {
+    syntax: FloatSyntax,
+    number: PhantomData<N>,
+}
 
-impl FloatParser {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        FloatParser {}
+impl<N: FloatT> FloatParser<N> {
+    pub fn new(syntax: FloatSyntax) -> Self {
+        FloatParser {
+            syntax,
+            number: PhantomData,
+        }
     }
 }
 
-impl ContextParserT<String> for FloatParser {
+impl<N> Clone for FloatParser<N> {
+    fn clone(&self) -> Self {
+        FloatParser {
+            syntax: self.syntax,
+            number: PhantomData,
+        }
+    }
+}
+
+impl<N: FloatT> ContextParserT<N> for FloatParser<N> {
     fn get_generic_error_message(&self) -> String {
         "float".to_string()
     }
@@ -27,12 +79,148 @@ impl ContextParserT<String> for FloatParser {
         ParserType::Float
     }
 
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<String>, Failure> {
-        match RegexParser::new(r"\d+\.\d*", "float").parse_from_context(ctx.clone()) {
-            Ok(res) => Ok(res),
-            Err(err) => Err(Failure::new("float", err.ctx, vec![ParserType::Float])),
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<N>, Failure> {
+        let rest = &ctx.txt[ctx.pos.min(ctx.txt.len())..];
+        let bytes = rest.as_bytes();
+
+        let len = match self.syntax {
+            FloatSyntax::Json => json(bytes),
+            FloatSyntax::Rust => rust(bytes),
+            FloatSyntax::Permissive => permissive(bytes),
+        };
+
+        // the scanned text only contains syntax `str::parse` accepts once separators are removed
+        let val = len.and_then(|len| rest[..len].replace('_', "").parse::<N>().ok());
+        match (len, val) {
+            // only permissive literals may spell out infinity
+            (Some(_), Some(val))
+                if val.is_finite().not()
+                    && matches!(self.syntax, FloatSyntax::Permissive).not() =>
+            {
+                Err(Failure::new(
+                    "float (out of range)",
+                    ctx,
+                    vec![ParserType::Float],
+                ))
+            }
+            (Some(len), Some(val)) => {
+                ctx.pos += len;
+                Ok(Success::new(val, ctx))
+            }
+            _ => Err(Failure::new(
+                Expected::Token("float".to_string()),
+                ctx,
+                vec![ParserType::Float],
+            )),
         }
     }
 }
 
-impl StringParserT<String> for FloatParser {}
+impl<N: FloatT> StringParserT<N> for FloatParser<N> {}
+
+fn is_digit(bytes: &[u8], i: usize) -> bool {
+    bytes.get(i).is_some_and(u8::is_ascii_digit)
+}
+
+/// Returns the end of the digits starting at `i`, optionally separated by `_`
+fn digits(bytes: &[u8], mut i: usize, separators: bool) -> usize {
+    while is_digit(bytes, i) || (separators && bytes.get(i) == Some(&b'_')) {
+        i += 1;
+    }
+    i
+}
+
+/// Returns the end of an exponent starting at `i`, or `i` if there is no complete exponent
+fn exponent(bytes: &[u8], i: usize, separators: bool) -> usize {
+    if matches!(bytes.get(i), Some(b'e' | b'E')).not() {
+        return i;
+    }
+
+    let mut j = i + 1;
+    if matches!(bytes.get(j), Some(b'+' | b'-')) {
+        j += 1;
+    }
+    if separators {
+        j = bytes[j..]
+            .iter()
+            .position(|&b| b != b'_')
+            .map_or(bytes.len(), |k| j + k);
+    }
+
+    if is_digit(bytes, j) {
+        digits(bytes, j, separators)
+    } else {
+        i
+    }
+}
+
+/// Scans `-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?`
+fn json(bytes: &[u8]) -> Option<usize> {
+    let mut i = usize::from(bytes.first() == Some(&b'-'));
+
+    match bytes.get(i) {
+        Some(b'0') => i += 1,
+        Some(b'1'..=b'9') => i = digits(bytes, i, false),
+        _ => return None,
+    }
+
+    if bytes.get(i) == Some(&b'.') && is_digit(bytes, i + 1) {
+        i = digits(bytes, i + 1, false);
+    }
+
+    Some(exponent(bytes, i, false))
+}
+
+/// Scans a Rust float literal without type suffix, which needs a fraction or an exponent
+fn rust(bytes: &[u8]) -> Option<usize> {
+    let mut i = usize::from(bytes.first() == Some(&b'-'));
+    if is_digit(bytes, i).not() {
+        return None;
+    }
+    i = digits(bytes, i, true);
+
+    let mut float = false;
+    if bytes.get(i) == Some(&b'.') {
+        if is_digit(bytes, i + 1) {
+            i = digits(bytes, i + 1, true);
+            float = true;
+        } else if bytes
+            .get(i + 1)
+            .is_some_and(|&b| b == b'.' || b == b'_' || b.is_ascii_alphabetic())
+            .not()
+        {
+            // `1.` is a float unless it is followed by a range, a field or a method
+            i += 1;
+            float = true;
+        }
+    }
+
+    let end = exponent(bytes, i, true);
+    (float || end != i).then_some(end)
+}
+
+/// Scans an optionally signed decimal float, `inf`, `infinity` or `nan` in any case
+fn permissive(bytes: &[u8]) -> Option<usize> {
+    let i = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+
+    for special in ["infinity", "inf", "nan"] {
+        if bytes
+            .get(i..i + special.len())
+            .is_some_and(|b| b.eq_ignore_ascii_case(special.as_bytes()))
+        {
+            return Some(i + special.len());
+        }
+    }
+
+    let int = digits(bytes, i, false);
+    let mut end = int;
+    if bytes.get(end) == Some(&b'.') {
+        end = digits(bytes, end + 1, false);
+    }
+
+    // at least one digit is needed before or after the point
+    if int == i && end <= int + 1 {
+        return None;
+    }
+    Some(exponent(bytes, end, false))
+}
@@ -8,14 +8,13 @@ use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParser
 /// let res = ForgetParser::new(ParserRc::new(StringParser::new("Hello"))).parse("Hello");
 /// assert_eq!(res.unwrap().val, ());
 /// ```
-#[derive(Clone)]
-pub struct ForgetParser<T> {
-    parser: ParserRc<dyn ContextParserT<T>>,
+pub struct ForgetParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
     generic_error: String,
 }
 
-impl<T> ForgetParser<T> {
-    pub fn new(parser: ParserRc<dyn ContextParserT<T>>) -> Self {
+impl<T, I: ?Sized> ForgetParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>) -> Self {
         let generic_error = format!("forgetting `{}`", parser.get_generic_error_message());
 
         ForgetParser {
@@ -25,7 +24,16 @@ impl<T> ForgetParser<T> {
     }
 }
 
-impl<T> ContextParserT<()> for ForgetParser<T> {
+impl<T, I: ?Sized> Clone for ForgetParser<T, I> {
+    fn clone(&self) -> Self {
+        ForgetParser {
+            parser: self.parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<(), I> for ForgetParser<T, I> {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -34,7 +42,7 @@ impl<T> ContextParserT<()> for ForgetParser<T> {
         ParserType::Forget
     }
 
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<()>, Failure> {
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<(), I>, Failure<I>> {
         match self.parser.parse_from_context(ctx) {
             Ok(res) => Ok(Success::new((), res.ctx)),
             Err(mut err) => {
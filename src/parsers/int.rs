@@ -0,0 +1,178 @@
+use std::{marker::PhantomData, ops::Not};
+
+use crate::{
+    Context, ContextParserT, Expected, Failure, ParserType, Separators, StringParserT, Success,
+};
+
+/// Trait for integers that can be parsed by an `IntParser`. It is implemented for all primitive
+/// integer types, only signed types accept a leading `-`.
+pub trait IntT: TryFrom<u128> + TryFrom<i128> {
+    /// Name of the type, used in error messages
+    const NAME: &'static str;
+    /// Whether the type accepts negative values
+    const SIGNED: bool;
+}
+
+macro_rules! int_impl {
+    ($signed:literal, $($t:ty),+) => {
+        $(
+            impl IntT for $t {
+                const NAME: &'static str = stringify!($t);
+                const SIGNED: bool = $signed;
+            }
+        )+
+    };
+}
+
+int_impl!(false, u8, u16, u32, u64, u128, usize);
+int_impl!(true, i8, i16, i32, i64, i128, isize);
+
+/// Parses an integer of an optional sign followed by ASCII digits, `0x`, `0o` and `0b` prefixes
+/// switch to hexadecimal, octal and binary digits. Whether `_` may separate digits is determined by
+/// `Separators`. Fails at the first digit that makes the value overflow the type.
+/// ## Example
+/// ```
+/// use microparsec::{IntParser, Separators, ContextParserT, StringParserT};
+///
+/// let res = IntParser::<i32>::new(Separators::Forbidden).parse("-42;");
+/// assert_eq!(res.clone().unwrap().val, -42);
+/// assert_eq!(res.unwrap().ctx.pos, 3);
+///
+/// let res = IntParser::<u32>::new(Separators::Allowed).parse("0xdead_beef");
+/// assert_eq!(res.unwrap().val, 0xdead_beef);
+///
+/// let res = IntParser::<i8>::new(Separators::Allowed).parse("-0b1000_0000");
+/// assert_eq!(res.unwrap().val, -128);
+///
+/// let res = IntParser::<u8>::new(Separators::Forbidden).parse("1024");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `u8 integer (overflow)` at 1:4"
+/// );
+///
+/// let res = IntParser::<u8>::new(Separators::Forbidden).parse("0x");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `hexadecimal digit` at 1:3"
+/// );
+/// ```
+pub struct IntParser<N> {
+    separators: Separators,
+    generic_error: String,
+    number: PhantomData<N>,
+}
+
+impl<N: IntT> IntParser<N> {
+    pub fn new(separators: Separators) -> Self {
+        IntParser {
+            separators,
+            generic_error: format!("{} integer", N::NAME),
+            number: PhantomData,
+        }
+    }
+}
+
+impl<N> Clone for IntParser<N> {
+    fn clone(&self) -> Self {
+        IntParser {
+            separators: self.separators,
+            generic_error: self.generic_error.clone(),
+            number: PhantomData,
+        }
+    }
+}
+
+impl<N: IntT> ContextParserT<N> for IntParser<N> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Integer
+    }
+
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<N>, Failure> {
+        let txt = ctx.txt.clone();
+        let rest = &txt[ctx.pos.min(txt.len())..];
+        let bytes = rest.as_bytes();
+        let mut i = 0;
+
+        let negative = N::SIGNED && bytes.first() == Some(&b'-');
+        if negative || bytes.first() == Some(&b'+') {
+            i += 1;
+        }
+
+        let (radix, name) = match bytes.get(i..i + 2) {
+            Some([b'0', b'x' | b'X']) => (16, "hexadecimal"),
+            Some([b'0', b'o' | b'O']) => (8, "octal"),
+            Some([b'0', b'b' | b'B']) => (2, "binary"),
+            _ => (10, "decimal"),
+        };
+        if radix != 10 {
+            i += 2;
+        }
+
+        let digit = |i: usize| bytes.get(i).and_then(|&b| (b as char).to_digit(radix));
+        if digit(i).is_none() {
+            // a bare prefix is not a valid integer
+            let exp = if radix == 10 {
+                Expected::Token(self.generic_error.clone())
+            } else {
+                ctx.pos += i;
+                Expected::Token(format!("{name} digit"))
+            };
+            return Err(Failure::new(exp, ctx, vec![ParserType::Integer]));
+        }
+
+        // accumulate the magnitude and check after every digit that the value still fits
+        let mut magnitude = 0_u128;
+        let mut val = None;
+        loop {
+            let d = match digit(i) {
+                Some(d) => d,
+                None if self.separators == Separators::Allowed
+                    && bytes.get(i) == Some(&b'_')
+                    && digit(i + 1).is_some() =>
+                {
+                    i += 1;
+                    continue;
+                }
+                None => break,
+            };
+
+            let next = magnitude
+                .checked_mul(radix as u128)
+                .and_then(|m| m.checked_add(d as u128));
+            val = next.and_then(|m| to_int::<N>(m, negative));
+            if val.is_none() {
+                ctx.pos += i;
+                return Err(Failure::new(
+                    format!("{} (overflow)", self.generic_error),
+                    ctx,
+                    vec![ParserType::Integer],
+                ));
+            }
+
+            magnitude = next.unwrap();
+            i += 1;
+        }
+
+        ctx.pos += i;
+        Ok(Success::new(val.unwrap(), ctx))
+    }
+}
+
+impl<N: IntT> StringParserT<N> for IntParser<N> {}
+
+/// Converts a magnitude to the signed or unsigned value, returns `None` if it does not fit
+fn to_int<N: IntT>(magnitude: u128, negative: bool) -> Option<N> {
+    if negative.not() {
+        return N::try_from(magnitude).ok();
+    }
+
+    // the magnitude of `i128::MIN` does not fit into an `i128`
+    if magnitude > i128::MIN.unsigned_abs() {
+        return None;
+    }
+    N::try_from((magnitude as i128).wrapping_neg()).ok()
+}
@@ -1,6 +1,8 @@
-use crate::{Context, ContextParserT, Failure, ParserType, StringParserT, Success};
+use std::ops::Not;
 
-/// Parses for an integer
+use crate::{Context, ContextParserT, Expected, Failure, ParserType, Span, StringParserT, Success};
+
+/// Parses for the ASCII digits of an integer, see `IntParser` for typed integers
 /// ## Example
 /// ```
 /// use microparsec::{IntegerParser, ContextParserT, StringParserT};
@@ -18,7 +20,7 @@ impl IntegerParser {
     }
 }
 
-impl ContextParserT<String> for IntegerParser {
+impl ContextParserT<Span> for IntegerParser {
     fn get_generic_error_message(&self) -> String {
         "integer".to_string()
     }
@@ -27,19 +29,24 @@ impl ContextParserT<String> for IntegerParser {
         ParserType::Integer
     }
 
-    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<String>, Failure> {
-        let integers: String = ctx.txt[ctx.pos..]
-            .chars()
-            .take_while(|c| c.is_numeric())
-            .collect();
-
-        if integers.is_empty() {
-            return Err(Failure::new("integer", ctx, vec![ParserType::Integer]));
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<Span>, Failure> {
+        let rest = &ctx.txt[ctx.pos..];
+        let len = rest
+            .find(|c: char| c.is_ascii_digit().not())
+            .unwrap_or(rest.len());
+
+        if len == 0 {
+            return Err(Failure::new(
+                Expected::Token("integer".to_string()),
+                ctx,
+                vec![ParserType::Integer],
+            ));
         }
 
-        ctx.pos += integers.len();
+        let integers = Span::new(ctx.txt.clone(), ctx.pos, ctx.pos + len);
+        ctx.pos += len;
         Ok(Success::new(integers, ctx))
     }
 }
 
-impl StringParserT<String> for IntegerParser {}
+impl StringParserT<Span> for IntegerParser {}
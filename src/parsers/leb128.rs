@@ -0,0 +1,135 @@
+use std::{marker::PhantomData, ops::Not};
+
+use crate::{Context, ContextParserT, Expected, Failure, ParserType, Success};
+
+/// Trait for integers that can be read as LEB128 varints by a `Leb128Parser`. It is implemented
+/// for all primitive integer types up to 64 bits, signed types are read as signed LEB128.
+pub trait Leb128T: TryFrom<u128> + TryFrom<i128> {
+    /// Name of the type, used in error messages
+    const NAME: &'static str;
+    /// Whether the type is read as signed LEB128
+    const SIGNED: bool;
+}
+
+macro_rules! leb128_impl {
+    ($signed:literal, $($t:ty),+) => {
+        $(
+            impl Leb128T for $t {
+                const NAME: &'static str = stringify!($t);
+                const SIGNED: bool = $signed;
+            }
+        )+
+    };
+}
+
+leb128_impl!(false, u8, u16, u32, u64, usize);
+leb128_impl!(true, i8, i16, i32, i64, isize);
+
+/// Parses a LEB128 variable length integer, fails if the value does not fit into the type
+/// ## Example
+/// ```
+/// use microparsec::{Leb128Parser, ContextParserT, SliceParserT};
+///
+/// let res = Leb128Parser::<u32>::new().parse(&[0xe5, 0x8e, 0x26, 0xff]);
+/// assert_eq!(res.clone().unwrap().val, 624485);
+/// assert_eq!(res.unwrap().ctx.pos, 3);
+///
+/// let res = Leb128Parser::<i32>::new().parse(&[0xc0, 0xbb, 0x78]);
+/// assert_eq!(res.unwrap().val, -123456);
+///
+/// let res = Leb128Parser::<u8>::new().parse(&[0x80, 0x02]);
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `leb128 u8 (overflow)` at byte 0"
+/// );
+/// ```
+pub struct Leb128Parser<N> {
+    generic_error: String,
+    number: PhantomData<N>,
+}
+
+impl<N: Leb128T> Leb128Parser<N> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Leb128Parser {
+            generic_error: format!("leb128 {}", N::NAME),
+            number: PhantomData,
+        }
+    }
+}
+
+impl<N> Clone for Leb128Parser<N> {
+    fn clone(&self) -> Self {
+        Leb128Parser {
+            generic_error: self.generic_error.clone(),
+            number: PhantomData,
+        }
+    }
+}
+
+impl<N: Leb128T> ContextParserT<N, [u8]> for Leb128Parser<N> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Leb128
+    }
+
+    fn parse_from_context(
+        &self,
+        mut ctx: Context<[u8]>,
+    ) -> Result<Success<N, [u8]>, Failure<[u8]>> {
+        // the last byte of a varint has the continuation bit cleared
+        let len = match ctx.txt[ctx.pos.min(ctx.txt.len())..]
+            .iter()
+            .position(|b| b & 0x80 == 0)
+        {
+            Some(i) => i + 1,
+            None => {
+                return Err(Failure::new(
+                    Expected::Token(self.generic_error.clone()),
+                    ctx,
+                    vec![ParserType::Leb128],
+                ))
+            }
+        };
+
+        match decode::<N>(&ctx.txt[ctx.pos..ctx.pos + len]) {
+            Some(val) => {
+                ctx.pos += len;
+                Ok(Success::new(val, ctx))
+            }
+            None => Err(Failure::new(
+                format!("{} (overflow)", self.generic_error),
+                ctx,
+                vec![ParserType::Leb128],
+            )),
+        }
+    }
+}
+
+/// Decodes the 7 bit groups of a varint, returns `None` if the value does not fit into the type
+fn decode<N: Leb128T>(groups: &[u8]) -> Option<N> {
+    let mut val = 0_u128;
+    for (i, group) in groups.iter().enumerate() {
+        let bits = (group & 0x7f) as u128;
+        let shift = 7 * i as u32;
+        if bits != 0 && (shift >= u128::BITS || (bits << shift) >> shift != bits) {
+            return None;
+        }
+        val |= bits.checked_shl(shift).unwrap_or(0);
+    }
+
+    if N::SIGNED.not() {
+        return N::try_from(val).ok();
+    }
+
+    // sign extend from the sign bit of the last group
+    let bits = 7 * groups.len() as u32;
+    let mut val = val as i128;
+    if bits < i128::BITS && groups[groups.len() - 1] & 0x40 != 0 {
+        val |= -1_i128 << bits;
+    }
+    N::try_from(val).ok()
+}
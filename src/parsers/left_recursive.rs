@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::{
+    Context, ContextParserT, ExpectedSet, Failure, ParserMutex, ParserRc, ParserType,
+    StringParserT, Success,
+};
+
+/// Seeds of the rule indexed by the address of the input and the position they are currently grown
+/// at, so a re-entrant parse of a different input never sees them
+type SeedTable<T, I> = HashMap<(usize, usize), Result<Success<T, I>, Failure<I>>>;
+
+/// Enables left-recursive rules like `expr := expr "-" number | number` by growing a seed
+/// (Warth et al.). <br>
+/// When the parser is re-entered at the same position during its own parse, the result of the
+/// previous iteration is returned instead of recursing. The first iteration fails there, every
+/// further iteration re-runs the rule until it no longer consumes more input. The rule should
+/// refer to itself through a `RecursiveParser` that is bound to the `LeftRecursiveParser`.
+/// ## Example
+/// ```
+/// use microparsec::{AnyParser, CombinatorT, IntegerParser, LeftRecursiveParser, ParserRc, RecursiveParser, StringParser, TupleParser, ContextParserT, StringParserT, parsers, tuple_parsers};
+///
+/// // expr := expr "-" number | number
+/// let expr_parser = RecursiveParser::new("expression");
+/// let number_parser = IntegerParser::new().map(|res| Ok(res.val.parse::<i64>().unwrap()));
+/// let sub_parser = TupleParser::new(tuple_parsers!(expr_parser.lazy(), StringParser::new("-"), number_parser.clone()))
+///     .map(|res| Ok(res.val.0 - res.val.2));
+///
+/// expr_parser.bind(ParserRc::new(LeftRecursiveParser::new(ParserRc::new(
+///     AnyParser::new(parsers!(sub_parser, number_parser)),
+/// ))));
+///
+/// let res = expr_parser.parse("10-2-3");
+/// assert_eq!(res.clone().unwrap().val, 5);
+/// assert_eq!(res.unwrap().ctx.pos, 6);
+///
+/// let res = expr_parser.parse("x");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `integer` at 1:1");
+/// ```
+pub struct LeftRecursiveParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    seeds: ParserRc<ParserMutex<SeedTable<T, I>>>,
+    generic_error: String,
+}
+
+impl<T: Clone, I: ?Sized> LeftRecursiveParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>) -> Self {
+        let generic_error = parser.get_generic_error_message();
+
+        LeftRecursiveParser {
+            parser,
+            seeds: ParserRc::new(ParserMutex::new(HashMap::new())),
+            generic_error,
+        }
+    }
+}
+
+impl<T, I: ?Sized> Clone for LeftRecursiveParser<T, I> {
+    fn clone(&self) -> Self {
+        LeftRecursiveParser {
+            parser: self.parser.clone(),
+            seeds: self.seeds.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T: Clone, I: ?Sized> ContextParserT<T, I> for LeftRecursiveParser<T, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::LeftRecursive
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
+        // the input is kept alive by the context while its seeds are in the table
+        let key = (ParserRc::as_ptr(&ctx.txt).cast::<()>() as usize, ctx.pos);
+
+        // re-entered while growing the seed at this position
+        if let Some(seed) = self.seeds.with(|seeds| seeds.get(&key).cloned()) {
+            return seed;
+        }
+
+        // the first seed expects nothing, so it never shows up in the errors of alternatives
+        let mut seed: Result<Success<T, I>, Failure<I>> = Err(Failure::new(
+            ExpectedSet::new(),
+            ctx.clone(),
+            vec![ParserType::LeftRecursive],
+        ));
+        self.seeds.with(|seeds| seeds.insert(key, seed.clone()));
+
+        // grow the seed until the rule stops consuming more input
+        loop {
+            match (self.parser.parse_from_context(ctx.clone()), &seed) {
+                (Ok(res), Err(_)) => seed = Ok(res),
+                (Ok(res), Ok(prev)) if res.ctx.pos > prev.ctx.pos => seed = Ok(res),
+                (Err(err), Err(_)) => seed = Err(err),
+                _ => break,
+            }
+
+            if seed.is_err() {
+                break;
+            }
+            self.seeds.with(|seeds| seeds.insert(key, seed.clone()));
+        }
+
+        self.seeds.with(|seeds| seeds.remove(&key));
+        seed.map_err(|mut err| {
+            err.p_type_stack.push(ParserType::LeftRecursive);
+            err
+        })
+    }
+}
+
+impl<T: Clone> StringParserT<T> for LeftRecursiveParser<T> {}
@@ -0,0 +1,89 @@
+use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, Success};
+
+/// Parses a block of bytes whose length is given by a preceding length parser, e.g. a
+/// `BinaryParser` or a `Leb128Parser`, and returns the bytes of the block
+/// ## Example
+/// ```
+/// use microparsec::{BinaryParser, Endian, LengthPrefixedParser, ParserRc, ContextParserT, SliceParserT};
+///
+/// let parser = LengthPrefixedParser::new(ParserRc::new(BinaryParser::<u16>::new(Endian::Big)));
+/// let res = parser.parse(&[0x00, 0x03, b'a', b'b', b'c', b'd']);
+/// assert_eq!(res.clone().unwrap().val, b"abc".to_vec());
+/// assert_eq!(res.unwrap().ctx.pos, 5);
+///
+/// let res = parser.parse(&[0x00, 0x03, b'a']);
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `block of 3 bytes (found 1)` at byte 2"
+/// );
+/// ```
+pub struct LengthPrefixedParser<L> {
+    length_parser: ParserRc<dyn ContextParserT<L, [u8]>>,
+    generic_error: String,
+}
+
+impl<L: TryInto<usize>> LengthPrefixedParser<L> {
+    pub fn new(length_parser: ParserRc<dyn ContextParserT<L, [u8]>>) -> Self {
+        let generic_error = format!(
+            "block prefixed by `{}`",
+            length_parser.get_generic_error_message()
+        );
+
+        LengthPrefixedParser {
+            length_parser,
+            generic_error,
+        }
+    }
+}
+
+impl<L> Clone for LengthPrefixedParser<L> {
+    fn clone(&self) -> Self {
+        LengthPrefixedParser {
+            length_parser: self.length_parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<L: TryInto<usize>> ContextParserT<Vec<u8>, [u8]> for LengthPrefixedParser<L> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::LengthPrefixed
+    }
+
+    fn parse_from_context(
+        &self,
+        ctx: Context<[u8]>,
+    ) -> Result<Success<Vec<u8>, [u8]>, Failure<[u8]>> {
+        let res = match self.length_parser.parse_from_context(ctx) {
+            Ok(res) => res,
+            Err(mut err) => {
+                err.p_type_stack.push(ParserType::LengthPrefixed);
+                return Err(err);
+            }
+        };
+
+        let mut ctx = res.ctx;
+        let remaining = ctx.txt.len().saturating_sub(ctx.pos);
+        match res.val.try_into() {
+            Ok(len) if len <= remaining => {
+                let block = ctx.txt[ctx.pos..ctx.pos + len].to_vec();
+                ctx.pos += len;
+                Ok(Success::new(block, ctx))
+            }
+            Ok(len) => Err(Failure::new(
+                format!("block of {len} bytes (found {remaining})"),
+                ctx,
+                vec![ParserType::LengthPrefixed],
+            )),
+            Err(_) => Err(Failure::new(
+                format!("block of at most {remaining} bytes"),
+                ctx,
+                vec![ParserType::LengthPrefixed],
+            )),
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Context, ContextParserT, Failure, ParserType, StringParserT, Success};
+use std::ops::Not;
+
+use crate::{Context, ContextParserT, Expected, Failure, ParserType, Span, StringParserT, Success};
 
 /// Parses for at least one letter
 /// ## Example
@@ -7,39 +9,64 @@ use crate::{Context, ContextParserT, Failure, ParserType, StringParserT, Success
 ///
 /// let res = LettersParser::new().parse("Hello");
 /// assert_eq!(res.unwrap().val, "Hello");
+///
+/// let res = LettersParser::ascii().parse("Straße");
+/// assert_eq!(res.unwrap().val, "Stra");
 /// ```
 #[derive(Clone)]
-pub struct LettersParser {}
+pub struct LettersParser {
+    ascii: bool,
+}
 
 impl LettersParser {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        LettersParser {}
+        LettersParser { ascii: false }
+    }
+
+    /// Creates a new `LettersParser` that only accepts ASCII letters
+    pub fn ascii() -> Self {
+        LettersParser { ascii: true }
     }
 }
 
-impl ContextParserT<String> for LettersParser {
+impl ContextParserT<Span> for LettersParser {
     fn get_generic_error_message(&self) -> String {
-        "letters".to_string()
+        if self.ascii {
+            "ascii letters".to_string()
+        } else {
+            "letters".to_string()
+        }
     }
 
     fn get_parser_type(&self) -> ParserType {
         ParserType::Letters
     }
 
-    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<String>, Failure> {
-        let letters: String = ctx.txt[ctx.pos..]
-            .chars()
-            .take_while(|c| c.is_alphabetic())
-            .collect();
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<Span>, Failure> {
+        let rest = &ctx.txt[ctx.pos..];
+        let len = rest
+            .find(|c: char| {
+                if self.ascii {
+                    c.is_ascii_alphabetic().not()
+                } else {
+                    c.is_alphabetic().not()
+                }
+            })
+            .unwrap_or(rest.len());
 
-        if letters.is_empty() {
-            return Err(Failure::new("letters", ctx, vec![ParserType::Letters]));
+        if len == 0 {
+            return Err(Failure::new(
+                Expected::Token(self.get_generic_error_message()),
+                ctx,
+                vec![ParserType::Letters],
+            ));
         }
 
-        ctx.pos += letters.len();
+        let letters = Span::new(ctx.txt.clone(), ctx.pos, ctx.pos + len);
+        ctx.pos += len;
         Ok(Success::new(letters, ctx))
     }
 }
 
-impl StringParserT<String> for LettersParser {}
+impl StringParserT<Span> for LettersParser {}
@@ -1,6 +1,9 @@
 use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT, Success};
 
-/// Parses as many times as possible, returns an error if no parsing was successful
+/// Parses as many times as possible, returns an error if no parsing was successful. For zero or
+/// more parses see `RepeatParser`. <br>
+/// If the supplied parser succeeds without consuming input the repetition stops instead of looping
+/// forever, an error is returned if nothing was parsed before.
 /// ## Example
 /// ```
 /// use microparsec::{ManyParser, ContextParserT, StringParserT, ParserRc, RegexParser};
@@ -14,14 +17,13 @@ use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParser
 ///     vec!["1".to_string(), "2".to_string(), "3".to_string()]
 /// );
 /// ```
-#[derive(Clone)]
-pub struct ManyParser<T> {
-    parser: ParserRc<dyn ContextParserT<T>>,
+pub struct ManyParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
     generic_error: String,
 }
 
-impl<T> ManyParser<T> {
-    pub fn new(parser: ParserRc<dyn ContextParserT<T>>) -> Self {
+impl<T, I: ?Sized> ManyParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>) -> Self {
         let generic_error = format!("many `{}`", parser.get_generic_error_message());
 
         ManyParser {
@@ -31,7 +33,16 @@ impl<T> ManyParser<T> {
     }
 }
 
-impl<T> ContextParserT<Vec<T>> for ManyParser<T> {
+impl<T, I: ?Sized> Clone for ManyParser<T, I> {
+    fn clone(&self) -> Self {
+        ManyParser {
+            parser: self.parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<Vec<T>, I> for ManyParser<T, I> {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -40,11 +51,15 @@ impl<T> ContextParserT<Vec<T>> for ManyParser<T> {
         ParserType::Many
     }
 
-    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<Vec<T>>, Failure> {
+    fn parse_from_context(&self, mut ctx: Context<I>) -> Result<Success<Vec<T>, I>, Failure<I>> {
         let mut ret = Vec::new();
 
         loop {
             match self.parser.parse_from_context(ctx.clone()) {
+                Ok(res) if res.ctx.pos == ctx.pos && ret.is_empty() => {
+                    return Err(non_consuming_failure(&self.parser, ctx, ParserType::Many));
+                }
+                Ok(res) if res.ctx.pos == ctx.pos => return Ok(Success::new(ret, ctx)),
                 Ok(res) => {
                     ctx = res.ctx;
                     ret.push(res.val);
@@ -60,3 +75,17 @@ impl<T> ContextParserT<Vec<T>> for ManyParser<T> {
 }
 
 impl<T> StringParserT<Vec<T>> for ManyParser<T> {}
+
+/// Returns the failure of a repeating parser whose supplied parser succeeded without consuming
+/// input, which would otherwise cause an infinite loop
+pub(crate) fn non_consuming_failure<T, I: ?Sized>(
+    parser: &ParserRc<dyn ContextParserT<T, I>>,
+    ctx: Context<I>,
+    p_type: ParserType,
+) -> Failure<I> {
+    Failure::new(
+        format!("{} to consume input", parser.get_generic_error_message()),
+        ctx,
+        vec![parser.get_parser_type(), p_type],
+    )
+}
@@ -0,0 +1,114 @@
+use crate::{
+    non_consuming_failure, Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT,
+    Success,
+};
+
+/// Parses with the supplied parser until the end parser succeeds, returns the values and the value
+/// of the end parser, or an error if neither parser matched
+/// ## Example
+/// ```
+/// use microparsec::{ManyTillParser, ContextParserT, StringParserT, ParserRc, RegexParser, StringParser};
+///
+/// let char_parser = ParserRc::new(RegexParser::new(r".", "anything"));
+/// let end_parser = ParserRc::new(StringParser::new("-->"));
+/// let parser = ManyTillParser::new(char_parser, end_parser);
+///
+/// let res = parser.parse("ab-->c");
+/// let (chars, end) = res.clone().unwrap().val;
+/// assert_eq!(chars, vec!["a", "b"]);
+/// assert_eq!(end, "-->");
+/// assert_eq!(res.unwrap().ctx.pos, 5);
+///
+/// let res = parser.parse("ab");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `-->` or `anything` at 1:3"
+/// );
+/// ```
+pub struct ManyTillParser<T, E, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    end_parser: ParserRc<dyn ContextParserT<E, I>>,
+    generic_error: String,
+}
+
+impl<T, E, I: ?Sized> ManyTillParser<T, E, I> {
+    pub fn new(
+        parser: ParserRc<dyn ContextParserT<T, I>>,
+        end_parser: ParserRc<dyn ContextParserT<E, I>>,
+    ) -> Self {
+        let generic_error = format!(
+            "many `{}` till `{}`",
+            parser.get_generic_error_message(),
+            end_parser.get_generic_error_message()
+        );
+
+        ManyTillParser {
+            parser,
+            end_parser,
+            generic_error,
+        }
+    }
+}
+
+impl<T, E, I: ?Sized> Clone for ManyTillParser<T, E, I> {
+    fn clone(&self) -> Self {
+        ManyTillParser {
+            parser: self.parser.clone(),
+            end_parser: self.end_parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, E, I: ?Sized> ContextParserT<(Vec<T>, E), I> for ManyTillParser<T, E, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::ManyTill
+    }
+
+    fn parse_from_context(
+        &self,
+        mut ctx: Context<I>,
+    ) -> Result<Success<(Vec<T>, E), I>, Failure<I>> {
+        let mut ret = Vec::new();
+
+        loop {
+            let end_err = match self.end_parser.parse_from_context(ctx.clone()) {
+                Ok(res) => return Ok(Success::new((ret, res.val), res.ctx)),
+                Err(err) => err,
+            };
+
+            match self.parser.parse_from_context(ctx.clone()) {
+                Ok(res) if res.ctx.pos == ctx.pos => {
+                    return Err(non_consuming_failure(
+                        &self.parser,
+                        ctx,
+                        ParserType::ManyTill,
+                    ));
+                }
+                Ok(res) => {
+                    ctx = res.ctx;
+                    ret.push(res.val);
+                }
+                // report the failure that progressed furthest, merge them if equally far
+                Err(mut err) => {
+                    if end_err.ctx.pos > err.ctx.pos {
+                        err = end_err;
+                    } else if end_err.ctx.pos == err.ctx.pos {
+                        let mut exp = end_err.exp;
+                        exp.merge(err.exp);
+                        err = Failure::new(exp, err.ctx, vec![]);
+                    }
+
+                    err.p_type_stack.push(ParserType::ManyTill);
+                    return Err(err);
+                }
+            }
+        }
+    }
+}
+
+impl<T, E> StringParserT<(Vec<T>, E)> for ManyTillParser<T, E> {}
@@ -1,6 +1,17 @@
-use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT, Success};
+use crate::{
+    Context, ContextParserT, Failure, ParserRc, ParserSyncT, ParserType, StringParserT, Success,
+};
 
-/// Maps the result of a parser to a new value
+/// Mapping function of a `MapParser`
+trait MapperT<T, M, I: ?Sized>: Fn(Success<T, I>) -> Result<M, String> + ParserSyncT {}
+
+impl<T, M, I: ?Sized, F> MapperT<T, M, I> for F where
+    F: Fn(Success<T, I>) -> Result<M, String> + ParserSyncT
+{
+}
+
+/// Maps the result of a parser to a new value. The mapper can be any closure and may capture its
+/// environment.
 /// ## Example
 /// ```
 /// use microparsec::{MapParser, IntegerParser, SequenceParser, StringParser, ParserRc, ContextParserT, StringParserT, parsers};
@@ -18,30 +29,45 @@ use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParser
 ///                          |res| Ok(res.val.parse::<u32>().unwrap()))
 ///             .parse("234");
 /// assert_eq!(res.unwrap().val, 234);
+///
+/// let offset = 100;
+/// let res = MapParser::new(ParserRc::new(IntegerParser::new()),
+///                          move |res| Ok(res.val.parse::<u32>().unwrap() + offset))
+///             .parse("234");
+/// assert_eq!(res.unwrap().val, 334);
 /// ```
-#[derive(Clone)]
-pub struct MapParser<T, M> {
-    parser: ParserRc<dyn ContextParserT<T>>,
-    mapper: fn(Success<T>) -> Result<M, String>,
+pub struct MapParser<T, M, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    mapper: ParserRc<dyn MapperT<T, M, I>>,
     generic_error: String,
 }
 
-impl<T, M> MapParser<T, M> {
-    pub fn new(
-        parser: ParserRc<dyn ContextParserT<T>>,
-        mapper: fn(Success<T>) -> Result<M, String>,
-    ) -> Self {
+impl<T, M, I: ?Sized> MapParser<T, M, I> {
+    pub fn new<F>(parser: ParserRc<dyn ContextParserT<T, I>>, mapper: F) -> Self
+    where
+        F: Fn(Success<T, I>) -> Result<M, String> + ParserSyncT + 'static,
+    {
         let generic_error = format!("mapping `{}`", parser.get_generic_error_message());
 
         MapParser {
             parser,
-            mapper,
+            mapper: ParserRc::new(mapper),
             generic_error,
         }
     }
 }
 
-impl<T, M> ContextParserT<M> for MapParser<T, M> {
+impl<T, M, I: ?Sized> Clone for MapParser<T, M, I> {
+    fn clone(&self) -> Self {
+        MapParser {
+            parser: self.parser.clone(),
+            mapper: self.mapper.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, M, I: ?Sized> ContextParserT<M, I> for MapParser<T, M, I> {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -50,7 +76,7 @@ impl<T, M> ContextParserT<M> for MapParser<T, M> {
         ParserType::Map
     }
 
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<M>, Failure> {
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<M, I>, Failure<I>> {
         let res = match self.parser.parse_from_context(ctx.clone()) {
             Ok(res) => res,
             Err(mut err) => {
@@ -0,0 +1,154 @@
+use std::{collections::HashMap, ops::Not};
+
+use crate::{
+    Context, ContextParserT, Failure, ParserMutex, ParserRc, ParserType, StringParserT, Success,
+};
+
+/// Cache statistics of a `MemoParser`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MemoStats {
+    /// Number of parses answered from the cache
+    pub hits: usize,
+    /// Number of parses that had to be run
+    pub misses: usize,
+}
+
+impl MemoStats {
+    /// Returns the ratio of hits to all lookups, or `0.0` if there were no lookups
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+struct MemoTable<T, I: ?Sized> {
+    txt: Option<ParserRc<I>>,
+    results: HashMap<usize, Result<Success<T, I>, Failure<I>>>,
+    stats: MemoStats,
+}
+
+/// Caches the results of the supplied parser by position (packrat parsing), so alternatives that
+/// re-parse the same prefix only run it once. <br>
+/// The cache belongs to the `MemoParser` instance and its clones, it is reset whenever a different
+/// input is parsed. It keeps the last input and its results alive until then, or until `clear` is
+/// called.
+/// ## Example
+/// ```
+/// use microparsec::{AnyParser, LettersParser, MemoParser, ParserRc, SequenceParser, StringParser, ContextParserT, StringParserT, parsers};
+///
+/// let word_parser = MemoParser::new(ParserRc::new(LettersParser::new()));
+/// let parser = AnyParser::new(parsers!(
+///     SequenceParser::new(parsers!(word_parser.clone(), StringParser::new("!"))),
+///     SequenceParser::new(parsers!(word_parser.clone(), StringParser::new("?")))
+/// ));
+///
+/// let res = parser.parse("Hello?");
+/// assert_eq!(res.unwrap().val, vec!["Hello".to_string(), "?".to_string()]);
+/// assert_eq!(word_parser.stats().hits, 1);
+/// assert_eq!(word_parser.stats().misses, 1);
+/// assert_eq!(word_parser.stats().hit_rate(), 0.5);
+/// ```
+pub struct MemoParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    table: ParserRc<ParserMutex<MemoTable<T, I>>>,
+    generic_error: String,
+}
+
+impl<T: Clone, I: ?Sized> MemoParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>) -> Self {
+        let generic_error = parser.get_generic_error_message();
+
+        MemoParser {
+            parser,
+            table: ParserRc::new(ParserMutex::new(MemoTable {
+                txt: None,
+                results: HashMap::new(),
+                stats: MemoStats::default(),
+            })),
+            generic_error,
+        }
+    }
+
+    /// Returns the cache statistics
+    pub fn stats(&self) -> MemoStats {
+        self.table.with(|table| table.stats)
+    }
+
+    /// Clears the cache and its statistics
+    pub fn clear(&self) {
+        self.table.with(|table| {
+            table.txt = None;
+            table.results.clear();
+            table.stats = MemoStats::default();
+        })
+    }
+}
+
+impl<T, I: ?Sized> Clone for MemoParser<T, I> {
+    fn clone(&self) -> Self {
+        MemoParser {
+            parser: self.parser.clone(),
+            table: self.table.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T: Clone, I: ?Sized> ContextParserT<T, I> for MemoParser<T, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Memo
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
+        let cached = self.table.with(|table| {
+            if table
+                .txt
+                .as_ref()
+                .is_some_and(|txt| ParserRc::ptr_eq(txt, &ctx.txt))
+                .not()
+            {
+                table.txt = Some(ctx.txt.clone());
+                table.results.clear();
+            }
+
+            let cached = table.results.get(&ctx.pos).cloned();
+            match cached {
+                Some(_) => table.stats.hits += 1,
+                None => table.stats.misses += 1,
+            }
+            cached
+        });
+
+        if let Some(res) = cached {
+            return res;
+        }
+
+        // the table must not be borrowed while parsing, the parser might be recursive
+        let txt = ctx.txt.clone();
+        let pos = ctx.pos;
+        let res = self.parser.parse_from_context(ctx).map_err(|mut err| {
+            err.p_type_stack.push(ParserType::Memo);
+            err
+        });
+
+        // a re-entrant parse of a different input may have reset the table in the meantime
+        self.table.with(|table| {
+            if table
+                .txt
+                .as_ref()
+                .is_some_and(|current| ParserRc::ptr_eq(current, &txt))
+            {
+                table.results.insert(pos, res.clone());
+            }
+        });
+        res
+    }
+}
+
+impl<T: Clone> StringParserT<T> for MemoParser<T> {}
@@ -1,6 +1,4 @@
-use std::ops::Not;
-
-use crate::{Context, ContextParserT, Failure, ParserType, StringParserT, Success};
+use crate::{Context, ContextParserT, Failure, ParserType, Span, StringParserT, Success};
 
 /// Matches until a character from the target is seen
 /// ### Example
@@ -24,25 +22,26 @@ impl NotParser {
     }
 }
 
-impl ContextParserT<String> for NotParser {
+impl ContextParserT<Span> for NotParser {
     fn get_generic_error_message(&self) -> String {
         self.target.to_string()
     }
 
     fn get_parser_type(&self) -> ParserType {
-        ParserType::String
+        ParserType::Not
     }
 
-    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<String>, Failure> {
-        let word: String = ctx.txt[ctx.pos..]
-            .chars()
-            .take_while(|c| self.target.contains(*c).not())
-            .collect();
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<Span>, Failure> {
+        let rest = &ctx.txt[ctx.pos..];
+        let len = rest
+            .find(|c: char| self.target.contains(c))
+            .unwrap_or(rest.len());
 
-        ctx.pos += word.len();
+        let word = Span::new(ctx.txt.clone(), ctx.pos, ctx.pos + len);
+        ctx.pos += len;
 
         Ok(Success::new(word, ctx))
     }
 }
 
-impl StringParserT<String> for NotParser {}
+impl StringParserT<Span> for NotParser {}
@@ -12,14 +12,13 @@ use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParser
 /// let res = OptionalParser::new(ParserRc::new(hello_world_parser)).parse("Hallo World");
 /// assert_eq!(res.unwrap().val.is_none(), true);
 /// ```
-#[derive(Clone)]
-pub struct OptionalParser<T> {
-    parser: ParserRc<dyn ContextParserT<T>>,
+pub struct OptionalParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
     generic_error: String,
 }
 
-impl<T> OptionalParser<T> {
-    pub fn new(parser: ParserRc<dyn ContextParserT<T>>) -> Self {
+impl<T, I: ?Sized> OptionalParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>) -> Self {
         let generic_error = format!("optional `{}`", parser.get_generic_error_message());
 
         OptionalParser {
@@ -29,7 +28,16 @@ impl<T> OptionalParser<T> {
     }
 }
 
-impl<T> ContextParserT<Option<T>> for OptionalParser<T> {
+impl<T, I: ?Sized> Clone for OptionalParser<T, I> {
+    fn clone(&self) -> Self {
+        OptionalParser {
+            parser: self.parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<Option<T>, I> for OptionalParser<T, I> {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -38,7 +46,7 @@ impl<T> ContextParserT<Option<T>> for OptionalParser<T> {
         ParserType::Optional
     }
 
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<Option<T>>, Failure> {
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<Option<T>, I>, Failure<I>> {
         match self.parser.parse_from_context(ctx.clone()) {
             Ok(res) => Ok(Success::new(Some(res.val), res.ctx)),
             Err(_) => Ok(Success::new(None, ctx)),
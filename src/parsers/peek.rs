@@ -0,0 +1,123 @@
+use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT, Success};
+
+/// Runs a supplied parser and returns its result without advancing the context (lookahead)
+/// ## Example
+/// ```
+/// use microparsec::{ParserRc, PeekParser, StringParser, ContextParserT, StringParserT};
+///
+/// let hello_parser = StringParser::new("Hello");
+/// let res = PeekParser::new(ParserRc::new(hello_parser)).parse("Hello World");
+/// assert_eq!(res.clone().unwrap().val, "Hello");
+/// assert_eq!(res.unwrap().ctx.pos, 0);
+/// ```
+pub struct PeekParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    generic_error: String,
+}
+
+impl<T, I: ?Sized> PeekParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>) -> Self {
+        let generic_error = format!("peek `{}`", parser.get_generic_error_message());
+
+        PeekParser {
+            parser,
+            generic_error,
+        }
+    }
+}
+
+impl<T, I: ?Sized> Clone for PeekParser<T, I> {
+    fn clone(&self) -> Self {
+        PeekParser {
+            parser: self.parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<T, I> for PeekParser<T, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Peek
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
+        match self.parser.parse_from_context(ctx.clone()) {
+            Ok(res) => Ok(Success::new(res.val, ctx)),
+            Err(mut err) => {
+                err.p_type_stack.push(ParserType::Peek);
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<T> StringParserT<T> for PeekParser<T> {}
+
+/// Succeeds without advancing the context only if the supplied parser fails (negative lookahead)
+/// ## Example
+/// ```
+/// use microparsec::{ParserRc, NotFollowedByParser, StringParser, ContextParserT, StringParserT};
+///
+/// let hello_parser = StringParser::new("Hello");
+/// let parser = NotFollowedByParser::new(ParserRc::new(hello_parser));
+///
+/// let res = parser.parse("Hallo World");
+/// assert_eq!(res.unwrap().ctx.pos, 0);
+///
+/// let res = parser.parse("Hello World");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `not followed by `Hello`` at 1:1"
+/// );
+/// ```
+pub struct NotFollowedByParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    generic_error: String,
+}
+
+impl<T, I: ?Sized> NotFollowedByParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>) -> Self {
+        let generic_error = format!("not followed by `{}`", parser.get_generic_error_message());
+
+        NotFollowedByParser {
+            parser,
+            generic_error,
+        }
+    }
+}
+
+impl<T, I: ?Sized> Clone for NotFollowedByParser<T, I> {
+    fn clone(&self) -> Self {
+        NotFollowedByParser {
+            parser: self.parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<(), I> for NotFollowedByParser<T, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::NotFollowedBy
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<(), I>, Failure<I>> {
+        match self.parser.parse_from_context(ctx.clone()) {
+            Ok(_) => Err(Failure::new(
+                self.generic_error.clone(),
+                ctx,
+                vec![ParserType::NotFollowedBy],
+            )),
+            Err(_) => Ok(Success::new((), ctx)),
+        }
+    }
+}
+
+impl<T> StringParserT<()> for NotFollowedByParser<T> {}
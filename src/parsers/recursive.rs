@@ -0,0 +1,173 @@
+use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT, Success};
+
+#[cfg(feature = "thread-safe")]
+use std::sync::{OnceLock as OnceCell, Weak};
+#[cfg(not(feature = "thread-safe"))]
+use std::{cell::OnceCell, rc::Weak};
+
+type Definition<T, I> = OnceCell<ParserRc<dyn ContextParserT<T, I>>>;
+
+/// Forward-declared parser that is bound to its definition after creation, making it possible to
+/// write grammars that refer to themselves. <br>
+/// The `RecursiveParser` owns its definition, the definition itself should only refer to the
+/// parser through the weak handles returned by `lazy`, otherwise a reference cycle is created.
+/// ## Example
+/// ```
+/// use microparsec::{CombinatorT, ParserRc, RecursiveParser, StringParser, ContextParserT, StringParserT};
+///
+/// // parentheses := "(" parentheses? ")"
+/// let parentheses = RecursiveParser::new("parentheses");
+/// parentheses.bind(ParserRc::new(
+///     parentheses
+///         .lazy()
+///         .optional()
+///         .between(StringParser::new("("), StringParser::new(")"))
+///         .map(|res| Ok(res.val.unwrap_or(0) + 1)),
+/// ));
+///
+/// let res = parentheses.parse("((()))");
+/// assert_eq!(res.unwrap().val, 3);
+///
+/// let res = parentheses.parse("(()");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `)` at 1:4");
+/// ```
+pub struct RecursiveParser<T, I: ?Sized = str> {
+    definition: ParserRc<Definition<T, I>>,
+    generic_error: String,
+}
+
+impl<T, I: ?Sized> RecursiveParser<T, I> {
+    /// Creates a new unbound `RecursiveParser`, the name is used as its generic error message
+    pub fn new<S: AsRef<str>>(name: S) -> Self {
+        RecursiveParser {
+            definition: ParserRc::new(OnceCell::new()),
+            generic_error: name.as_ref().to_string(),
+        }
+    }
+
+    /// Binds the parser to its definition, panics if it was already bound
+    pub fn bind(&self, parser: ParserRc<dyn ContextParserT<T, I>>) {
+        if self.definition.set(parser).is_err() {
+            panic!("Recursive parser `{}` is already bound", self.generic_error);
+        }
+    }
+
+    /// Returns a weak handle to the parser to be used inside of its own definition
+    pub fn lazy(&self) -> LazyParser<T, I> {
+        LazyParser {
+            definition: ParserRc::downgrade(&self.definition),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> Clone for RecursiveParser<T, I> {
+    fn clone(&self) -> Self {
+        RecursiveParser {
+            definition: self.definition.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<T, I> for RecursiveParser<T, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Recursive
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
+        let parser = match self.definition.get() {
+            Some(parser) => parser,
+            None => panic!("Recursive parser `{}` was never bound", self.generic_error),
+        };
+
+        match parser.parse_from_context(ctx) {
+            Ok(res) => Ok(res),
+            Err(mut err) => {
+                err.p_type_stack.push(ParserType::Recursive);
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<T> StringParserT<T> for RecursiveParser<T> {}
+
+/// Weak handle to the definition of a `RecursiveParser`, see `RecursiveParser::lazy`. <br>
+/// Fails when parsing if the `RecursiveParser` was dropped, so a grammar has to keep it instead of
+/// only its handles. Panics if it was never bound.
+/// ## Example
+/// ```
+/// use microparsec::{CombinatorT, ParserRc, RecursiveParser, StringParser, ContextParserT, StringParserT};
+///
+/// let parentheses = RecursiveParser::new("parentheses");
+/// parentheses.bind(ParserRc::new(
+///     parentheses
+///         .lazy()
+///         .optional()
+///         .between(StringParser::new("("), StringParser::new(")"))
+///         .map(|res| Ok(res.val.unwrap_or(0) + 1)),
+/// ));
+/// let lazy = parentheses.lazy();
+/// drop(parentheses);
+///
+/// let res = lazy.parse("()");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `parentheses (dropped)` at 1:1"
+/// );
+/// ```
+pub struct LazyParser<T, I: ?Sized = str> {
+    definition: Weak<Definition<T, I>>,
+    generic_error: String,
+}
+
+impl<T, I: ?Sized> Clone for LazyParser<T, I> {
+    fn clone(&self) -> Self {
+        LazyParser {
+            definition: self.definition.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<T, I> for LazyParser<T, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Recursive
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
+        let definition = match self.definition.upgrade() {
+            Some(definition) => definition,
+            None => {
+                return Err(Failure::new(
+                    format!("{} (dropped)", self.generic_error),
+                    ctx,
+                    vec![ParserType::Recursive],
+                ))
+            }
+        };
+        let parser = match definition.get() {
+            Some(parser) => parser.clone(),
+            None => panic!("Recursive parser `{}` was never bound", self.generic_error),
+        };
+
+        match parser.parse_from_context(ctx) {
+            Ok(res) => Ok(res),
+            Err(mut err) => {
+                err.p_type_stack.push(ParserType::Recursive);
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<T> StringParserT<T> for LazyParser<T> {}
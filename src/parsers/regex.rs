@@ -1,8 +1,12 @@
 use regex::Regex;
 
-use crate::{Context, ContextParserT, Failure, ParserType, StringParserT, Success};
+/// Error returned by `RegexParser::try_new` if the supplied regex is invalid
+pub use regex::Error as RegexError;
 
-/// Parses for a given regex pattern
+use crate::{Context, ContextParserT, Expected, Failure, ParserType, Span, StringParserT, Success};
+
+/// Parses for a given regex pattern. The pattern is compiled once at initialization and is always
+/// anchored at the current position of the context.
 /// ## Example
 /// ```
 /// use microparsec::{RegexParser, ContextParserT, StringParserT};
@@ -14,28 +18,47 @@ use crate::{Context, ContextParserT, Failure, ParserType, StringParserT, Success
 /// let res = number_parser.parse("+12 45 6890");
 /// assert_eq!(
 ///     res.unwrap_err().get_error_message(),
-///     "[Parser error] Expected `Phone number` at position: 0"
+///     "[Parser error] Expected `Phone number` at 1:1"
 /// );
+///
+/// let res = RegexParser::try_new(r"\d{2", "digits");
+/// assert!(res.is_err());
 /// ```
 #[derive(Clone)]
 pub struct RegexParser {
-    regex: String,
+    regex: Regex,
     generic_error: String,
 }
 
 impl RegexParser {
+    /// Creates a new `RegexParser`, panics if the regex is invalid
     pub fn new<A: AsRef<str>, B: AsRef<str>>(regex: A, expected: B) -> Self {
-        let regex = regex.as_ref().to_string();
+        match RegexParser::try_new(regex, expected) {
+            Ok(parser) => parser,
+            Err(err) => panic!("Invalid regex: {err}"),
+        }
+    }
+
+    /// Creates a new `RegexParser`, returns an error if the regex is invalid
+    pub fn try_new<A: AsRef<str>, B: AsRef<str>>(
+        regex: A,
+        expected: B,
+    ) -> Result<Self, RegexError> {
+        // validate the pattern on its own first, so it can't escape the anchoring group. The group
+        // is closed on a new line that ends a trailing comment of a verbose pattern, `(?x)` makes
+        // the new line itself insignificant
+        Regex::new(regex.as_ref())?;
+        let regex = Regex::new(&format!("\\A(?:{}(?x)\n)", regex.as_ref()))?;
         let generic_error = expected.as_ref().to_string();
 
-        RegexParser {
+        Ok(RegexParser {
             regex,
             generic_error,
-        }
+        })
     }
 }
 
-impl ContextParserT<String> for RegexParser {
+impl ContextParserT<Span> for RegexParser {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -44,27 +67,19 @@ impl ContextParserT<String> for RegexParser {
         ParserType::Regex
     }
 
-    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<String>, Failure> {
-        let regex = match Regex::new(&self.regex) {
-            Ok(regex) => regex,
-            Err(_) => panic!("Invalid regex: {}", self.regex),
-        };
-
-        let sliced_ctx = &ctx.txt[ctx.pos..];
-        let mat = regex.find(sliced_ctx);
-        if let Some(mat) = mat {
-            if mat.start() == 0 {
-                ctx.pos += mat.end();
-                return Ok(Success::new(mat.as_str().to_string(), ctx));
-            }
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<Span>, Failure> {
+        if let Some(mat) = self.regex.find(&ctx.txt[ctx.pos..]) {
+            let mat = Span::new(ctx.txt.clone(), ctx.pos, ctx.pos + mat.end());
+            ctx.pos += mat.len();
+            return Ok(Success::new(mat, ctx));
         }
 
         Err(Failure::new(
-            &self.generic_error,
+            Expected::Token(self.generic_error.clone()),
             ctx,
             vec![ParserType::Regex],
         ))
     }
 }
 
-impl StringParserT<String> for RegexParser {}
+impl StringParserT<Span> for RegexParser {}
@@ -0,0 +1,128 @@
+use std::ops::{Bound, RangeBounds};
+
+use crate::{
+    non_consuming_failure, Context, ContextParserT, ExpectedSet, Failure, ParserRc, ParserType,
+    StringParserT, Success,
+};
+
+/// Parses as many times as possible but at most `max` times, returns an error if less than `min`
+/// parses were successful. The bounds are given as a Rust range, `0..` parses zero or more times.
+/// <br>
+/// If the supplied parser succeeds without consuming input the repetition stops, an error is
+/// returned if it stopped before `min` parses.
+/// ## Example
+/// ```
+/// use microparsec::{RepeatParser, ContextParserT, StringParserT, ParserRc, RegexParser};
+///
+/// let digit_parser = ParserRc::new(RegexParser::new(r"\d", "digit"));
+///
+/// let res = RepeatParser::new(digit_parser.clone(), 2..=3).parse("1234");
+/// assert_eq!(res.unwrap().val, vec!["1".to_string(), "2".to_string(), "3".to_string()]);
+///
+/// let res = RepeatParser::new(digit_parser.clone(), 0..).parse("abc");
+/// assert_eq!(res.unwrap().val.len(), 0);
+///
+/// let res = RepeatParser::new(digit_parser, 2..=3).parse("1abc");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `between 2 and 3 `digit` (found 1)` at 1:2"
+/// );
+/// ```
+pub struct RepeatParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    min: usize,
+    max: Option<usize>,
+    generic_error: String,
+}
+
+impl<T, I: ?Sized> RepeatParser<T, I> {
+    pub fn new<R: RangeBounds<usize>>(
+        parser: ParserRc<dyn ContextParserT<T, I>>,
+        range: R,
+    ) -> Self {
+        let (min, max, generic_error) = repetition(range, &parser.get_generic_error_message());
+
+        RepeatParser {
+            parser,
+            min,
+            max,
+            generic_error,
+        }
+    }
+}
+
+impl<T, I: ?Sized> Clone for RepeatParser<T, I> {
+    fn clone(&self) -> Self {
+        RepeatParser {
+            parser: self.parser.clone(),
+            min: self.min,
+            max: self.max,
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<Vec<T>, I> for RepeatParser<T, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Repeat
+    }
+
+    fn parse_from_context(&self, mut ctx: Context<I>) -> Result<Success<Vec<T>, I>, Failure<I>> {
+        let mut ret = Vec::new();
+
+        while self.max.is_none_or(|max| ret.len() < max) {
+            match self.parser.parse_from_context(ctx.clone()) {
+                Ok(res) if res.ctx.pos == ctx.pos && ret.len() < self.min => {
+                    return Err(non_consuming_failure(&self.parser, ctx, ParserType::Repeat));
+                }
+                Ok(res) if res.ctx.pos == ctx.pos => break,
+                Ok(res) => {
+                    ctx = res.ctx;
+                    ret.push(res.val);
+                }
+                Err(mut err) if ret.len() < self.min => {
+                    err.exp =
+                        ExpectedSet::from(format!("{} (found {})", self.generic_error, ret.len()));
+                    err.p_type_stack.push(ParserType::Repeat);
+                    return Err(err);
+                }
+                Err(_) => break,
+            }
+        }
+
+        Ok(Success::new(ret, ctx))
+    }
+}
+
+impl<T> StringParserT<Vec<T>> for RepeatParser<T> {}
+
+/// Converts the bounds of a Rust range into a minimum and an optional maximum, and builds the
+/// generic error message of repeating `expected` within them
+pub(crate) fn repetition<R: RangeBounds<usize>>(
+    range: R,
+    expected: &str,
+) -> (usize, Option<usize>, String) {
+    let min = match range.start_bound() {
+        Bound::Included(min) => *min,
+        Bound::Excluded(min) => min + 1,
+        Bound::Unbounded => 0,
+    };
+    let max = match range.end_bound() {
+        Bound::Included(max) => Some(*max),
+        Bound::Excluded(max) => Some(max.checked_sub(1).expect("Range must not be empty")),
+        Bound::Unbounded => None,
+    };
+    assert!(max.is_none_or(|max| min <= max), "Range must not be empty");
+
+    let message = match max {
+        Some(max) if max == min => format!("exactly {min} `{expected}`"),
+        Some(max) => format!("between {min} and {max} `{expected}`"),
+        None => format!("at least {min} `{expected}`"),
+    };
+
+    (min, max, message)
+}
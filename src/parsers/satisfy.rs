@@ -0,0 +1,198 @@
+use std::ops::Not;
+
+use crate::{
+    Context, ContextParserT, Expected, Failure, ParserRc, ParserSyncT, ParserType, StringParserT,
+    Success,
+};
+
+/// Predicate of a `SatisfyParser` or a `TakeWhileParser`
+pub(crate) trait CharPredicateT: Fn(char) -> bool + ParserSyncT {}
+
+impl<F: Fn(char) -> bool + ParserSyncT> CharPredicateT for F {}
+
+/// Parses a single character that satisfies the predicate and returns it, fails if the position is
+/// not a character boundary of the input
+fn parse_char<F: Fn(char) -> bool>(
+    mut ctx: Context,
+    predicate: F,
+    expected: &str,
+    p_type: ParserType,
+) -> Result<Success<char>, Failure> {
+    let Some(rest) = ctx.txt.get(ctx.pos..) else {
+        return Err(Failure::new(Expected::EndOfInput, ctx, vec![p_type]));
+    };
+
+    match rest.chars().next() {
+        Some(c) if predicate(c) => {
+            ctx.pos += c.len_utf8();
+            Ok(Success::new(c, ctx))
+        }
+        _ => Err(Failure::new(
+            Expected::Token(expected.to_string()),
+            ctx,
+            vec![p_type],
+        )),
+    }
+}
+
+/// Parses a single character that satisfies a predicate and returns it
+/// ## Example
+/// ```
+/// use microparsec::{SatisfyParser, ContextParserT, StringParserT};
+///
+/// let res = SatisfyParser::new(|c| c.is_ascii_hexdigit(), "hex digit").parse("f0");
+/// assert_eq!(res.clone().unwrap().val, 'f');
+/// assert_eq!(res.unwrap().ctx.pos, 1);
+///
+/// let res = SatisfyParser::new(|c| c.is_ascii_hexdigit(), "hex digit").parse("g0");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `hex digit` at 1:1"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct SatisfyParser {
+    predicate: ParserRc<dyn CharPredicateT>,
+    generic_error: String,
+}
+
+impl SatisfyParser {
+    /// Creates a new `SatisfyParser` with the predicate, the name is used as its generic error
+    /// message
+    pub fn new<F, S>(predicate: F, expected: S) -> Self
+    where
+        F: Fn(char) -> bool + ParserSyncT + 'static,
+        S: AsRef<str>,
+    {
+        SatisfyParser {
+            predicate: ParserRc::new(predicate),
+            generic_error: expected.as_ref().to_string(),
+        }
+    }
+}
+
+impl ContextParserT<char> for SatisfyParser {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Satisfy
+    }
+
+    fn parse_from_context(&self, ctx: Context) -> Result<Success<char>, Failure> {
+        parse_char(
+            ctx,
+            &*self.predicate,
+            &self.generic_error,
+            ParserType::Satisfy,
+        )
+    }
+}
+
+impl StringParserT<char> for SatisfyParser {}
+
+/// Parses a single character that is one of the characters of the set and returns it
+/// ## Example
+/// ```
+/// use microparsec::{OneOfParser, ContextParserT, StringParserT};
+///
+/// let res = OneOfParser::new("+-").parse("-1");
+/// assert_eq!(res.unwrap().val, '-');
+///
+/// let res = OneOfParser::new("+-").parse("1");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `one of [+-]` at 1:1"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct OneOfParser {
+    chars: String,
+    generic_error: String,
+}
+
+impl OneOfParser {
+    /// Creates a new `OneOfParser` with the specified set of characters
+    pub fn new<S: AsRef<str>>(chars: S) -> Self {
+        assert!(chars.as_ref().is_empty().not(), "Chars must not be empty");
+
+        OneOfParser {
+            chars: chars.as_ref().to_string(),
+            generic_error: format!("one of [{}]", chars.as_ref()),
+        }
+    }
+}
+
+impl ContextParserT<char> for OneOfParser {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::OneOf
+    }
+
+    fn parse_from_context(&self, ctx: Context) -> Result<Success<char>, Failure> {
+        parse_char(
+            ctx,
+            |c| self.chars.contains(c),
+            &self.generic_error,
+            ParserType::OneOf,
+        )
+    }
+}
+
+impl StringParserT<char> for OneOfParser {}
+
+/// Parses a single character that is none of the characters of the set and returns it, fails at
+/// the end of input
+/// ## Example
+/// ```
+/// use microparsec::{NoneOfParser, ContextParserT, StringParserT};
+///
+/// let res = NoneOfParser::new("\"\\").parse("a\"");
+/// assert_eq!(res.unwrap().val, 'a');
+///
+/// let res = NoneOfParser::new("\"\\").parse("\"");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `none of [\"\\]` at 1:1"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct NoneOfParser {
+    chars: String,
+    generic_error: String,
+}
+
+impl NoneOfParser {
+    /// Creates a new `NoneOfParser` with the specified set of characters
+    pub fn new<S: AsRef<str>>(chars: S) -> Self {
+        NoneOfParser {
+            chars: chars.as_ref().to_string(),
+            generic_error: format!("none of [{}]", chars.as_ref()),
+        }
+    }
+}
+
+impl ContextParserT<char> for NoneOfParser {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::NoneOf
+    }
+
+    fn parse_from_context(&self, ctx: Context) -> Result<Success<char>, Failure> {
+        parse_char(
+            ctx,
+            |c| self.chars.contains(c).not(),
+            &self.generic_error,
+            ParserType::NoneOf,
+        )
+    }
+}
+
+impl StringParserT<char> for NoneOfParser {}
@@ -0,0 +1,214 @@
+use crate::{
+    non_consuming_failure, Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT,
+    Success, Trailing,
+};
+
+/// Parses for zero or more occurrences of a parser separated by a separator parser, the values of
+/// the separator are discarded. How a trailing separator is handled is determined by `Trailing`.
+/// ## Example
+/// ```
+/// use microparsec::{ParserRc, SepByParser, IntegerParser, StringParser, Trailing, ContextParserT, StringParserT};
+///
+/// let integer_parser = ParserRc::new(IntegerParser::new());
+/// let comma_parser = ParserRc::new(StringParser::new(","));
+/// let parser = SepByParser::new(integer_parser.clone(), comma_parser.clone(), Trailing::Forbidden);
+///
+/// let res = parser.parse("1,2,3");
+/// assert_eq!(res.unwrap().val, vec!["1".to_string(), "2".to_string(), "3".to_string()]);
+///
+/// let res = parser.parse("");
+/// assert_eq!(res.unwrap().val.len(), 0);
+///
+/// let res = parser.parse("1,2,");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `integer` at 1:5");
+///
+/// let res = SepByParser::new(integer_parser, comma_parser, Trailing::Optional).parse("1,2,");
+/// assert_eq!(res.unwrap().ctx.pos, 4);
+/// ```
+pub struct SepByParser<T, S, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    separator: ParserRc<dyn ContextParserT<S, I>>,
+    trailing: Trailing,
+    generic_error: String,
+}
+
+impl<T, S, I: ?Sized> SepByParser<T, S, I> {
+    pub fn new(
+        parser: ParserRc<dyn ContextParserT<T, I>>,
+        separator: ParserRc<dyn ContextParserT<S, I>>,
+        trailing: Trailing,
+    ) -> Self {
+        let generic_error = format!(
+            "zero or more `{}` separated by `{}`",
+            parser.get_generic_error_message(),
+            separator.get_generic_error_message()
+        );
+
+        SepByParser {
+            parser,
+            separator,
+            trailing,
+            generic_error,
+        }
+    }
+}
+
+impl<T, S, I: ?Sized> Clone for SepByParser<T, S, I> {
+    fn clone(&self) -> Self {
+        SepByParser {
+            parser: self.parser.clone(),
+            separator: self.separator.clone(),
+            trailing: self.trailing,
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, S, I: ?Sized> ContextParserT<Vec<T>, I> for SepByParser<T, S, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::SepBy
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<Vec<T>, I>, Failure<I>> {
+        separated(
+            &self.parser,
+            &self.separator,
+            self.trailing,
+            0,
+            ParserType::SepBy,
+            ctx,
+        )
+    }
+}
+
+impl<T, S> StringParserT<Vec<T>> for SepByParser<T, S> {}
+
+/// Parses for one or more occurrences of a parser separated by a separator parser, the values of
+/// the separator are discarded. How a trailing separator is handled is determined by `Trailing`.
+/// ## Example
+/// ```
+/// use microparsec::{ParserRc, SepBy1Parser, LettersParser, StringParser, Trailing, ContextParserT, StringParserT};
+///
+/// let parser = SepBy1Parser::new(
+///     ParserRc::new(LettersParser::new()),
+///     ParserRc::new(StringParser::new(";")),
+///     Trailing::Required,
+/// );
+///
+/// let res = parser.parse("a;b;");
+/// assert_eq!(res.unwrap().val, vec!["a".to_string(), "b".to_string()]);
+///
+/// let res = parser.parse("a;b");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `;` at 1:4");
+///
+/// let res = parser.parse("");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `letters` at 1:1");
+/// ```
+pub struct SepBy1Parser<T, S, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
+    separator: ParserRc<dyn ContextParserT<S, I>>,
+    trailing: Trailing,
+    generic_error: String,
+}
+
+impl<T, S, I: ?Sized> SepBy1Parser<T, S, I> {
+    pub fn new(
+        parser: ParserRc<dyn ContextParserT<T, I>>,
+        separator: ParserRc<dyn ContextParserT<S, I>>,
+        trailing: Trailing,
+    ) -> Self {
+        let generic_error = format!(
+            "one or more `{}` separated by `{}`",
+            parser.get_generic_error_message(),
+            separator.get_generic_error_message()
+        );
+
+        SepBy1Parser {
+            parser,
+            separator,
+            trailing,
+            generic_error,
+        }
+    }
+}
+
+impl<T, S, I: ?Sized> Clone for SepBy1Parser<T, S, I> {
+    fn clone(&self) -> Self {
+        SepBy1Parser {
+            parser: self.parser.clone(),
+            separator: self.separator.clone(),
+            trailing: self.trailing,
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, S, I: ?Sized> ContextParserT<Vec<T>, I> for SepBy1Parser<T, S, I> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::SepBy1
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<Vec<T>, I>, Failure<I>> {
+        separated(
+            &self.parser,
+            &self.separator,
+            self.trailing,
+            1,
+            ParserType::SepBy1,
+            ctx,
+        )
+    }
+}
+
+impl<T, S> StringParserT<Vec<T>> for SepBy1Parser<T, S> {}
+
+/// Shared implementation of `SepByParser` and `SepBy1Parser`
+fn separated<T, S, I: ?Sized>(
+    parser: &ParserRc<dyn ContextParserT<T, I>>,
+    separator: &ParserRc<dyn ContextParserT<S, I>>,
+    trailing: Trailing,
+    min: usize,
+    p_type: ParserType,
+    mut ctx: Context<I>,
+) -> Result<Success<Vec<T>, I>, Failure<I>> {
+    let mut ret = Vec::new();
+
+    loop {
+        let start = ctx.pos;
+
+        match parser.parse_from_context(ctx.clone()) {
+            Ok(res) => {
+                ctx = res.ctx;
+                ret.push(res.val);
+            }
+            // nothing parsed yet, no separator was consumed
+            Err(_) if ret.is_empty() && min == 0 => return Ok(Success::new(ret, ctx)),
+            // a separator was consumed and must be followed by another value
+            Err(mut err) if ret.is_empty() || trailing == Trailing::Forbidden => {
+                err.p_type_stack.push(p_type);
+                return Err(err);
+            }
+            Err(_) => return Ok(Success::new(ret, ctx)),
+        }
+
+        match separator.parse_from_context(ctx.clone()) {
+            Ok(res) if res.ctx.pos == start => {
+                return Err(non_consuming_failure(parser, res.ctx, p_type));
+            }
+            Ok(res) => ctx = res.ctx,
+            Err(mut err) if trailing == Trailing::Required => {
+                err.p_type_stack.push(p_type);
+                return Err(err);
+            }
+            Err(_) => return Ok(Success::new(ret, ctx)),
+        }
+    }
+}
@@ -15,14 +15,13 @@ use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParser
 ///     vec!["Hello".to_string(), "  ".to_string(), "World".to_string()]
 /// );
 /// ```
-#[derive(Clone)]
-pub struct SequenceParser<T> {
-    parsers: Vec<ParserRc<dyn ContextParserT<T>>>,
+pub struct SequenceParser<T, I: ?Sized = str> {
+    parsers: Vec<ParserRc<dyn ContextParserT<T, I>>>,
     generic_error: String,
 }
 
-impl<T> SequenceParser<T> {
-    pub fn new(parsers: Vec<ParserRc<dyn ContextParserT<T>>>) -> Self {
+impl<T, I: ?Sized> SequenceParser<T, I> {
+    pub fn new(parsers: Vec<ParserRc<dyn ContextParserT<T, I>>>) -> Self {
         let generic_error = parsers
             .iter()
             .map(|p| p.get_generic_error_message())
@@ -36,7 +35,16 @@ impl<T> SequenceParser<T> {
     }
 }
 
-impl<T> ContextParserT<Vec<T>> for SequenceParser<T> {
+impl<T, I: ?Sized> Clone for SequenceParser<T, I> {
+    fn clone(&self) -> Self {
+        SequenceParser {
+            parsers: self.parsers.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<Vec<T>, I> for SequenceParser<T, I> {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -45,7 +53,7 @@ impl<T> ContextParserT<Vec<T>> for SequenceParser<T> {
         ParserType::Sequence
     }
 
-    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<Vec<T>>, Failure> {
+    fn parse_from_context(&self, mut ctx: Context<I>) -> Result<Success<Vec<T>, I>, Failure<I>> {
         let mut result = Vec::new();
         for parser in self.parsers.iter() {
             match parser.parse_from_context(ctx.clone()) {
@@ -1,4 +1,6 @@
-use crate::{Context, ContextParserT, Failure, ParserType, StringParserT, Success};
+use std::ops::Not;
+
+use crate::{Context, ContextParserT, Expected, Failure, ParserType, Span, StringParserT, Success};
 
 /// Parses for at least one and as many spaces as possible
 /// ## Example
@@ -14,39 +16,67 @@ use crate::{Context, ContextParserT, Failure, ParserType, StringParserT, Success
 ///     res.unwrap().val,
 ///     vec!["Hello".to_string(), "  ".to_string(), "World".to_string()]
 /// );
+///
+/// let res = SpacesParser::ascii().parse("\u{a0} ");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `ascii spaces` at 1:1"
+/// );
 /// ```
 #[derive(Clone)]
-pub struct SpacesParser {}
+pub struct SpacesParser {
+    ascii: bool,
+}
 
 impl SpacesParser {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        SpacesParser {}
+        SpacesParser { ascii: false }
+    }
+
+    /// Creates a new `SpacesParser` that only accepts ASCII spaces
+    pub fn ascii() -> Self {
+        SpacesParser { ascii: true }
     }
 }
 
-impl ContextParserT<String> for SpacesParser {
+impl ContextParserT<Span> for SpacesParser {
     fn get_generic_error_message(&self) -> String {
-        "spaces".to_string()
+        if self.ascii {
+            "ascii spaces".to_string()
+        } else {
+            "spaces".to_string()
+        }
     }
 
     fn get_parser_type(&self) -> ParserType {
         ParserType::Spaces
     }
 
-    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<String>, Failure> {
-        let whitespace: String = ctx.txt[ctx.pos..]
-            .chars()
-            .take_while(|c| c.is_whitespace())
-            .collect();
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<Span>, Failure> {
+        let rest = &ctx.txt[ctx.pos..];
+        let len = rest
+            .find(|c: char| {
+                if self.ascii {
+                    c.is_ascii_whitespace().not()
+                } else {
+                    c.is_whitespace().not()
+                }
+            })
+            .unwrap_or(rest.len());
 
-        if whitespace.is_empty() {
-            return Err(Failure::new("spaces", ctx, vec![ParserType::Spaces]));
+        if len == 0 {
+            return Err(Failure::new(
+                Expected::Token(self.get_generic_error_message()),
+                ctx,
+                vec![ParserType::Spaces],
+            ));
         }
 
-        ctx.pos += whitespace.len();
+        let whitespace = Span::new(ctx.txt.clone(), ctx.pos, ctx.pos + len);
+        ctx.pos += len;
         Ok(Success::new(whitespace, ctx))
     }
 }
 
-impl StringParserT<String> for SpacesParser {}
+impl StringParserT<Span> for SpacesParser {}
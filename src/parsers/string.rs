@@ -1,32 +1,124 @@
 use std::ops::Not;
 
-use crate::{Context, ContextParserT, Failure, ParserType, StringParserT, Success};
+use unicode_normalization::{char::canonical_combining_class, UnicodeNormalization};
 
-/// Parses for a specific target string
+use crate::{
+    Context, ContextParserT, Expected, Failure, Matching, ParserType, Span, StringParserT, Success,
+};
+
+/// Parses for a specific target string, how it is compared with the input is determined by
+/// `Matching`. The text is returned as it appeared in the input.
 /// ### Example
 /// ```
-/// use microparsec::{StringParser, ContextParserT, StringParserT};
+/// use microparsec::{Matching, StringParser, ContextParserT, StringParserT};
 ///
 /// let res = StringParser::new("Hello World").parse("Hello World");
 /// assert_eq!(res.unwrap().val, "Hello World");
+///
+/// let res = StringParser::with_matching("select", Matching::IgnoreAsciiCase).parse("SeLeCt *");
+/// assert_eq!(res.unwrap().val, "SeLeCt");
+///
+/// let res = StringParser::with_matching("straße", Matching::IgnoreCase).parse("STRASSE 1");
+/// assert_eq!(res.unwrap().val, "STRASSE");
+///
+/// let res = StringParser::with_matching("caf\u{e9}", Matching::Nfc).parse("cafe\u{301}!");
+/// assert_eq!(res.clone().unwrap().val, "cafe\u{301}");
+/// assert_eq!(res.unwrap().ctx.pos, 6);
 /// ```
 #[derive(Clone)]
 pub struct StringParser {
     target: String,
+    matching: Matching,
+    /// The target in the form it is compared in
+    normalized: String,
 }
 
 impl StringParser {
     /// Creates a new `StringParser` with the specified target string
     pub fn new<S: AsRef<str>>(target: S) -> Self {
+        StringParser::with_matching(target, Matching::Exact)
+    }
+
+    /// Creates a new `StringParser` with the specified target string that compares it with the
+    /// input as determined by `Matching`
+    pub fn with_matching<S: AsRef<str>>(target: S, matching: Matching) -> Self {
         assert!(target.as_ref().is_empty().not(), "Target must not be empty");
 
+        let target = target.as_ref().to_string();
+        let normalized = match matching {
+            Matching::Exact | Matching::IgnoreAsciiCase => String::new(),
+            Matching::IgnoreCase => fold_case(&target),
+            Matching::Nfc => target.nfc().collect(),
+        };
+
         StringParser {
-            target: target.as_ref().to_string(),
+            target,
+            matching,
+            normalized,
         }
     }
+
+    /// Returns the length of the input that matches the target
+    fn match_len(&self, txt: &str) -> Option<usize> {
+        match self.matching {
+            Matching::Exact => txt.starts_with(&self.target).then_some(self.target.len()),
+            Matching::IgnoreAsciiCase => txt
+                .get(..self.target.len())
+                .filter(|prefix| prefix.eq_ignore_ascii_case(&self.target))
+                .map(str::len),
+            Matching::IgnoreCase => {
+                // case folding maps every character on its own, so the input is folded until it
+                // stops being a prefix of the folded target
+                let mut folded = String::new();
+                for (i, c) in txt.char_indices() {
+                    folded.extend(fold_case_char(c));
+                    if folded == self.normalized {
+                        return Some(i + c.len_utf8());
+                    }
+                    if self.normalized.starts_with(&folded).not() {
+                        return None;
+                    }
+                }
+                None
+            }
+            Matching::Nfc => {
+                // the decomposed input only grows, so longer prefixes cannot match anymore once it
+                // has more characters than the decomposed target
+                let max = self.target.nfd().count();
+                let mut len = 0;
+                for (i, c) in txt.char_indices() {
+                    len += c.nfd().count();
+                    if len > max {
+                        return None;
+                    }
+
+                    // a match must not end in front of a combining mark belonging to it
+                    let end = i + c.len_utf8();
+                    let boundary = txt[end..]
+                        .chars()
+                        .next()
+                        .is_none_or(|next| canonical_combining_class(next) == 0);
+                    if boundary && txt[..end].nfc().eq(self.normalized.chars()) {
+                        return Some(end);
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Folds the case of a character by mapping it to upper case and back to lower case, the full
+/// mappings turn e.g. `ß` into `ss` and `ς` into `σ`
+fn fold_case_char(c: char) -> impl Iterator<Item = char> {
+    c.to_uppercase().flat_map(char::to_lowercase)
+}
+
+fn fold_case(txt: &str) -> String {
+    txt.chars().flat_map(fold_case_char).collect()
 }
 
-impl ContextParserT<String> for StringParser {
+impl ContextParserT<Span> for StringParser {
     fn get_generic_error_message(&self) -> String {
         self.target.to_string()
     }
@@ -35,18 +127,19 @@ impl ContextParserT<String> for StringParser {
         ParserType::String
     }
 
-    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<String>, Failure> {
-        if ctx.txt[ctx.pos..].starts_with(&self.target) {
-            ctx.pos += self.target.len();
-            return Ok(Success::new(self.target.clone(), ctx));
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<Span>, Failure> {
+        if let Some(len) = self.match_len(&ctx.txt[ctx.pos..]) {
+            let target = Span::new(ctx.txt.clone(), ctx.pos, ctx.pos + len);
+            ctx.pos += len;
+            return Ok(Success::new(target, ctx));
         }
 
         Err(Failure::new(
-            self.target.clone(),
+            Expected::Literal(self.target.clone()),
             ctx,
             vec![ParserType::String],
         ))
     }
 }
 
-impl StringParserT<String> for StringParser {}
+impl StringParserT<Span> for StringParser {}
@@ -0,0 +1,248 @@
+use std::ops::Not;
+
+use crate::{Context, ContextParserT, Expected, Failure, ParserType, StringParserT, Success};
+
+/// Delimiters of a `StringLiteralParser`
+#[derive(Clone, Copy, PartialEq)]
+enum Form {
+    Single,
+    Triple,
+    Raw,
+}
+
+/// Parses a quoted string literal and returns its unescaped value. Escapes are `\n`, `\t`, `\r`,
+/// `\0`, `\b`, `\f`, `\/`, an escaped quote or escape character, `\u{..}` with up to six hex digits
+/// and `\uXXXX` where a high surrogate must be followed by an escaped low surrogate. Invalid escapes
+/// fail at the position of their escape character.
+/// ## Example
+/// ```
+/// use microparsec::{CombinatorT, StringLiteralParser, ContextParserT, StringParserT};
+///
+/// let res = StringLiteralParser::new("\"'", '\\').parse(r#"'say \"hi\"\n' rest"#);
+/// assert_eq!(res.clone().unwrap().val, "say \"hi\"\n");
+/// assert_eq!(res.unwrap().ctx.pos, 14);
+///
+/// let res = StringLiteralParser::new("\"", '\\').parse(r#""\u00e9\uD83D\uDE00\u{1F980}""#);
+/// assert_eq!(res.unwrap().val, "é😀🦀");
+///
+/// let parser = StringLiteralParser::triple("\"", '\\')
+///     .or(StringLiteralParser::raw("\""))
+///     .or(StringLiteralParser::new("\"", '\\'));
+///
+/// let res = parser.parse("\"\"\"first\nsecond\"\"\"");
+/// assert_eq!(res.unwrap().val, "first\nsecond");
+///
+/// let res = parser.parse(r##"r#"C:\path "quoted""#"##);
+/// assert_eq!(res.unwrap().val, r#"C:\path "quoted""#);
+///
+/// let res = StringLiteralParser::new("\"", '\\').parse(r#""tab\q""#);
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `escape sequence` at 1:5"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct StringLiteralParser {
+    quotes: Vec<char>,
+    escape: char,
+    form: Form,
+}
+
+impl StringLiteralParser {
+    /// Creates a new `StringLiteralParser` for single line strings delimited by one of the quote
+    /// characters, with the specified escape character
+    pub fn new<S: AsRef<str>>(quotes: S, escape: char) -> Self {
+        StringLiteralParser::with_form(quotes, escape, Form::Single)
+    }
+
+    /// Creates a new `StringLiteralParser` for multi line strings delimited by three of the same
+    /// quote characters, with the specified escape character
+    pub fn triple<S: AsRef<str>>(quotes: S, escape: char) -> Self {
+        StringLiteralParser::with_form(quotes, escape, Form::Triple)
+    }
+
+    /// Creates a new `StringLiteralParser` for raw strings without escapes in the style of Rust,
+    /// `r"..."` or `r#"..."#` with any number of `#`
+    pub fn raw<S: AsRef<str>>(quotes: S) -> Self {
+        StringLiteralParser::with_form(quotes, '\\', Form::Raw)
+    }
+
+    fn with_form<S: AsRef<str>>(quotes: S, escape: char, form: Form) -> Self {
+        assert!(quotes.as_ref().is_empty().not(), "Quotes must not be empty");
+
+        StringLiteralParser {
+            quotes: quotes.as_ref().chars().collect(),
+            escape,
+            form,
+        }
+    }
+
+    /// Returns the closing delimiter and the length of the opening delimiter at the start of `txt`
+    fn delimiters(&self, txt: &str) -> Option<(String, usize)> {
+        let (prefix, hashes) = match self.form {
+            Form::Raw => {
+                let rest = txt.strip_prefix('r')?;
+                let hashes = rest.len() - rest.trim_start_matches('#').len();
+                (1 + hashes, hashes)
+            }
+            _ => (0, 0),
+        };
+
+        let quote = txt[prefix..].chars().next()?;
+        if self.quotes.contains(&quote).not() {
+            return None;
+        }
+
+        let close = match self.form {
+            Form::Single => quote.to_string(),
+            Form::Triple => quote.to_string().repeat(3),
+            Form::Raw => format!("{quote}{}", "#".repeat(hashes)),
+        };
+        if self.form == Form::Triple && txt[prefix..].starts_with(&close).not() {
+            return None;
+        }
+
+        let open = match self.form {
+            Form::Triple => close.len(),
+            _ => prefix + quote.len_utf8(),
+        };
+        Some((close, open))
+    }
+
+    /// Unescapes the escape sequence at `pos`, returns the character and the end of the sequence
+    /// or the expectation and the position of the failure
+    fn unescape(&self, txt: &str, pos: usize) -> Result<(char, usize), (Expected, usize)> {
+        let invalid = |exp: &str| (Expected::Token(exp.to_string()), pos);
+
+        let start = pos + self.escape.len_utf8();
+        let c = txt[start..]
+            .chars()
+            .next()
+            .ok_or_else(|| invalid("escape sequence"))?;
+        let end = start + c.len_utf8();
+
+        let unescaped = match c {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            '/' | '"' | '\'' => c,
+            'u' => return self.unescape_unicode(txt, pos, end),
+            c if c == self.escape || self.quotes.contains(&c) => c,
+            _ => return Err(invalid("escape sequence")),
+        };
+
+        Ok((unescaped, end))
+    }
+
+    /// Unescapes `\u{..}` or `\uXXXX` and a following low surrogate, `start` is the position after
+    /// the `u`
+    fn unescape_unicode(
+        &self,
+        txt: &str,
+        pos: usize,
+        start: usize,
+    ) -> Result<(char, usize), (Expected, usize)> {
+        let invalid = || (Expected::Token("unicode escape".to_string()), pos);
+
+        if txt[start..].starts_with('{') {
+            let len = txt[start + 1..].find('}').ok_or_else(invalid)?;
+            let hex = &txt[start + 1..start + 1 + len];
+            if hex.is_empty() || hex.len() > 6 || hex.chars().all(|c| c.is_ascii_hexdigit()).not() {
+                return Err(invalid());
+            }
+
+            let c = u32::from_str_radix(hex, 16)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(invalid)?;
+            return Ok((c, start + len + 2));
+        }
+
+        let code = hex4(txt, start).ok_or_else(invalid)?;
+        let end = start + 4;
+        match code {
+            0xd800..=0xdbff => {
+                // a high surrogate has to be followed by an escaped low surrogate
+                let low = txt[end..]
+                    .strip_prefix(self.escape)
+                    .and_then(|rest| rest.strip_prefix('u'))
+                    .map(|_| end + self.escape.len_utf8() + 1)
+                    .and_then(|low| hex4(txt, low))
+                    .filter(|low| (0xdc00..=0xdfff).contains(low))
+                    .ok_or((Expected::Token("low surrogate escape".to_string()), end))?;
+
+                let c = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                Ok((char::from_u32(c).unwrap(), end + self.escape.len_utf8() + 5))
+            }
+            _ => char::from_u32(code).map(|c| (c, end)).ok_or_else(invalid),
+        }
+    }
+}
+
+/// Parses four hex digits at `pos`
+fn hex4(txt: &str, pos: usize) -> Option<u32> {
+    let hex = txt.get(pos..pos + 4)?;
+    if hex.chars().all(|c| c.is_ascii_hexdigit()).not() {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok()
+}
+
+impl ContextParserT<String> for StringLiteralParser {
+    fn get_generic_error_message(&self) -> String {
+        "string literal".to_string()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::StringLiteral
+    }
+
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<String>, Failure> {
+        let txt = ctx.txt.clone();
+        let (close, open) = match self.delimiters(&txt[ctx.pos.min(txt.len())..]) {
+            Some(delimiters) => delimiters,
+            None => {
+                return Err(Failure::new(
+                    Expected::Token("string literal".to_string()),
+                    ctx,
+                    vec![ParserType::StringLiteral],
+                ))
+            }
+        };
+
+        let mut val = String::new();
+        let mut pos = ctx.pos + open;
+        loop {
+            if txt[pos..].starts_with(&close) {
+                ctx.pos = pos + close.len();
+                return Ok(Success::new(val, ctx));
+            }
+
+            let res = match txt[pos..].chars().next() {
+                // only triple quoted and raw strings may span multiple lines
+                None => Err((Expected::Literal(close.clone()), pos)),
+                Some('\n') if self.form == Form::Single => {
+                    Err((Expected::Literal(close.clone()), pos))
+                }
+                Some(c) if c == self.escape && self.form != Form::Raw => self.unescape(&txt, pos),
+                Some(c) => Ok((c, pos + c.len_utf8())),
+            };
+
+            match res {
+                Ok((c, end)) => {
+                    val.push(c);
+                    pos = end;
+                }
+                Err((exp, pos)) => {
+                    ctx.pos = pos;
+                    return Err(Failure::new(exp, ctx, vec![ParserType::StringLiteral]));
+                }
+            }
+        }
+    }
+}
+
+impl StringParserT<String> for StringLiteralParser {}
@@ -1,4 +1,6 @@
-use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT, Success};
+use crate::{
+    Context, ContextParserT, ExpectedSet, Failure, ParserRc, ParserType, StringParserT, Success,
+};
 
 /// If an any parser comes across a surely parser and it fails the any parser immediately fails as well
 /// ## Example
@@ -14,16 +16,15 @@ use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParser
 /// let hallo_world_parser = SequenceParser::new(parsers!(hallo_parser, space_parser, world_parser));
 ///
 /// let res = AnyParser::new(parsers!(hello_world_parser, hallo_world_parser)).parse("Hallo Welt");
-/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `surely `World`` at position: 6");
+/// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `surely `World`` at 1:7");
 /// ```
-#[derive(Clone)]
-pub struct SurelyParser<T> {
-    parser: ParserRc<dyn ContextParserT<T>>,
+pub struct SurelyParser<T, I: ?Sized = str> {
+    parser: ParserRc<dyn ContextParserT<T, I>>,
     generic_error: String,
 }
 
-impl<T> SurelyParser<T> {
-    pub fn new(parser: ParserRc<dyn ContextParserT<T>>) -> Self {
+impl<T, I: ?Sized> SurelyParser<T, I> {
+    pub fn new(parser: ParserRc<dyn ContextParserT<T, I>>) -> Self {
         let generic_error = format!("surely `{}`", parser.get_generic_error_message());
 
         SurelyParser {
@@ -33,7 +34,16 @@ impl<T> SurelyParser<T> {
     }
 }
 
-impl<T> ContextParserT<T> for SurelyParser<T> {
+impl<T, I: ?Sized> Clone for SurelyParser<T, I> {
+    fn clone(&self) -> Self {
+        SurelyParser {
+            parser: self.parser.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T, I: ?Sized> ContextParserT<T, I> for SurelyParser<T, I> {
     fn get_generic_error_message(&self) -> String {
         self.generic_error.clone()
     }
@@ -42,11 +52,11 @@ impl<T> ContextParserT<T> for SurelyParser<T> {
         ParserType::Surely
     }
 
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<T>, Failure> {
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>> {
         match self.parser.parse_from_context(ctx) {
             Ok(res) => Ok(res),
             Err(mut err) => {
-                err.exp = self.generic_error.clone();
+                err.exp = ExpectedSet::from(&self.generic_error);
                 err.p_type_stack.push(ParserType::Surely);
                 Err(err)
             }
@@ -0,0 +1,97 @@
+use std::ops::{Not, RangeBounds};
+
+use crate::{
+    repetition, CharPredicateT, Context, ContextParserT, Expected, Failure, ParserRc, ParserSyncT,
+    ParserType, Span, StringParserT, Success,
+};
+
+/// Parses as many characters satisfying a predicate as possible but at most `max`, returns an error
+/// if less than `min` characters matched. The bounds are given as a Rust range.
+/// ## Example
+/// ```
+/// use microparsec::{TakeWhileParser, ContextParserT, StringParserT};
+///
+/// let parser = TakeWhileParser::new(|c| c.is_ascii_hexdigit(), 2..=4, "hex digit");
+///
+/// let res = parser.parse("c0ffee");
+/// assert_eq!(res.clone().unwrap().val, "c0ff");
+/// assert_eq!(res.unwrap().ctx.pos, 4);
+///
+/// let res = parser.parse("cup");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `between 2 and 4 `hex digit` (found 1)` at 1:2"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct TakeWhileParser {
+    predicate: ParserRc<dyn CharPredicateT>,
+    min: usize,
+    max: Option<usize>,
+    generic_error: String,
+}
+
+impl TakeWhileParser {
+    /// Creates a new `TakeWhileParser` with the predicate and the bounds of the amount of
+    /// characters, the name of a single character is used in its generic error message
+    pub fn new<F, R, S>(predicate: F, range: R, expected: S) -> Self
+    where
+        F: Fn(char) -> bool + ParserSyncT + 'static,
+        R: RangeBounds<usize>,
+        S: AsRef<str>,
+    {
+        let (min, max, generic_error) = repetition(range, expected.as_ref());
+
+        TakeWhileParser {
+            predicate: ParserRc::new(predicate),
+            min,
+            max,
+            generic_error,
+        }
+    }
+}
+
+impl ContextParserT<Span> for TakeWhileParser {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::TakeWhile
+    }
+
+    fn parse_from_context(&self, mut ctx: Context) -> Result<Success<Span>, Failure> {
+        let Some(rest) = ctx.txt.get(ctx.pos..) else {
+            return Err(Failure::new(
+                Expected::EndOfInput,
+                ctx,
+                vec![ParserType::TakeWhile],
+            ));
+        };
+
+        let mut count = 0;
+        let mut len = 0;
+        for c in rest.chars() {
+            if self.max.is_some_and(|max| count == max) || (self.predicate)(c).not() {
+                break;
+            }
+            count += 1;
+            len += c.len_utf8();
+        }
+
+        if count < self.min {
+            ctx.pos += len;
+            return Err(Failure::new(
+                format!("{} (found {count})", self.generic_error),
+                ctx,
+                vec![ParserType::TakeWhile],
+            ));
+        }
+
+        let span = Span::new(ctx.txt.clone(), ctx.pos, ctx.pos + len);
+        ctx.pos += len;
+        Ok(Success::new(span, ctx))
+    }
+}
+
+impl StringParserT<Span> for TakeWhileParser {}
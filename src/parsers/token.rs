@@ -0,0 +1,120 @@
+use crate::{
+    Context, ContextParserT, Expected, Failure, ParserRc, ParserSyncT, ParserType, Success, TokenT,
+};
+
+/// Predicate of a `TokenParser`
+trait TokenPredicateT<T>: Fn(&T) -> bool + ParserSyncT {}
+
+impl<T, F: Fn(&T) -> bool + ParserSyncT> TokenPredicateT<T> for F {}
+
+/// Parses a single token of a token stream, either a token of a specific kind or any token that
+/// satisfies a predicate, and returns it
+/// ## Example
+/// ```
+/// use microparsec::{ChainLeftParser, CombinatorT, ParserRc, TokenParser, TokenT, ContextParserT, SliceParserT};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Token {
+///     Number(i64),
+///     Minus,
+/// }
+///
+/// impl TokenT for Token {
+///     type Kind = Token;
+///
+///     fn kind(&self) -> Token {
+///         self.clone()
+///     }
+/// }
+///
+/// let number_parser = TokenParser::satisfy(|t| matches!(t, Token::Number(_)), "number").map(|res| {
+///     match res.val {
+///         Token::Number(n) => Ok(n),
+///         _ => Err("number".to_string()),
+///     }
+/// });
+/// let minus_parser = TokenParser::new(Token::Minus).map(|_| Ok(|a: i64, b: i64| a - b));
+/// let parser = ChainLeftParser::new(ParserRc::new(number_parser), ParserRc::new(minus_parser));
+///
+/// let res = parser.parse(&[Token::Number(10), Token::Minus, Token::Number(2)]);
+/// assert_eq!(res.clone().unwrap().val, 8);
+/// assert_eq!(res.unwrap().ctx.pos, 3);
+///
+/// let res = parser.parse(&[Token::Number(10), Token::Minus]);
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `number` at token 2"
+/// );
+///
+/// let res = TokenParser::new(Token::Minus).parse(&[Token::Number(1)]);
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `Minus` at token 0"
+/// );
+/// ```
+pub struct TokenParser<T> {
+    predicate: ParserRc<dyn TokenPredicateT<T>>,
+    generic_error: String,
+}
+
+impl<T: TokenT> TokenParser<T> {
+    /// Creates a new `TokenParser` that parses tokens of the specified kind
+    pub fn new(kind: T::Kind) -> Self
+    where
+        T::Kind: ParserSyncT + 'static,
+    {
+        let generic_error = format!("{kind:?}");
+
+        TokenParser {
+            predicate: ParserRc::new(move |t: &T| t.kind() == kind),
+            generic_error,
+        }
+    }
+
+    /// Creates a new `TokenParser` that parses tokens satisfying the predicate, the name is used as
+    /// its generic error message
+    pub fn satisfy<F, S>(predicate: F, expected: S) -> Self
+    where
+        F: Fn(&T) -> bool + ParserSyncT + 'static,
+        S: AsRef<str>,
+    {
+        TokenParser {
+            predicate: ParserRc::new(predicate),
+            generic_error: expected.as_ref().to_string(),
+        }
+    }
+}
+
+impl<T> Clone for TokenParser<T> {
+    fn clone(&self) -> Self {
+        TokenParser {
+            predicate: self.predicate.clone(),
+            generic_error: self.generic_error.clone(),
+        }
+    }
+}
+
+impl<T: TokenT> ContextParserT<T, [T]> for TokenParser<T> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Token
+    }
+
+    fn parse_from_context(&self, mut ctx: Context<[T]>) -> Result<Success<T, [T]>, Failure<[T]>> {
+        match ctx.txt.get(ctx.pos) {
+            Some(token) if (self.predicate)(token) => {
+                let token = token.clone();
+                ctx.pos += 1;
+                Ok(Success::new(token, ctx))
+            }
+            _ => Err(Failure::new(
+                Expected::Token(self.generic_error.clone()),
+                ctx,
+                vec![ParserType::Token],
+            )),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Context, ContextParserT, Failure, ParserRc, ParserType, StringParserT, Success};
+
+/// Trait for tuples of parsers that can be run in sequence by a `TupleParser`. It is implemented
+/// for tuples of up to 8 `ParserRc<dyn ContextParserT<_, I>>`.
+pub trait TupleParsersT<I: ?Sized = str> {
+    /// Tuple of the values of the parsers
+    type Output;
+
+    /// Returns the generic error messages of the parsers joined in sequence
+    fn get_generic_error_message(&self) -> String;
+
+    /// Runs the parsers in sequence on the context
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<Self::Output, I>, Failure<I>>;
+}
+
+macro_rules! tuple_parsers_impl {
+    ($($t:ident $i:tt),+) => {
+        impl<$($t),+, I: ?Sized> TupleParsersT<I> for ($(ParserRc<dyn ContextParserT<$t, I>>,)+) {
+            type Output = ($($t,)+);
+
+            fn get_generic_error_message(&self) -> String {
+                [$(self.$i.get_generic_error_message()),+].join(" -> ")
+            }
+
+            #[allow(non_snake_case)]
+            fn parse_from_context(
+                &self,
+                ctx: Context<I>,
+            ) -> Result<Success<Self::Output, I>, Failure<I>> {
+                $(
+                    let ($t, ctx) = match self.$i.parse_from_context(ctx) {
+                        Ok(res) => (res.val, res.ctx),
+                        Err(mut err) => {
+                            err.p_type_stack.push(ParserType::Sequence);
+                            return Err(err);
+                        }
+                    };
+                )+
+
+                Ok(Success::new(($($t,)+), ctx))
+            }
+        }
+    };
+}
+
+tuple_parsers_impl!(A 0);
+tuple_parsers_impl!(A 0, B 1);
+tuple_parsers_impl!(A 0, B 1, C 2);
+tuple_parsers_impl!(A 0, B 1, C 2, D 3);
+tuple_parsers_impl!(A 0, B 1, C 2, D 3, E 4);
+tuple_parsers_impl!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_parsers_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_parsers_impl!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Parses for a sequence of parsers with different value types and returns the values as a tuple
+/// ## Example
+/// ```
+/// use microparsec::{CombinatorT, IntegerParser, StringParser, TupleParser, StringParserT, ContextParserT, tuple_parsers};
+///
+/// let key_parser = StringParser::new("port");
+/// let sign_parser = StringParser::new("-").optional();
+/// let value_parser = IntegerParser::new().map(|res| Ok(res.val.parse::<u32>().unwrap()));
+/// let res = TupleParser::new(tuple_parsers!(key_parser, StringParser::new("="), sign_parser, value_parser))
+///             .parse("port=8080");
+///
+/// let (key, equals, sign, value) = res.unwrap().val;
+/// assert_eq!(
+///     (key.as_str(), equals.as_str(), sign, value),
+///     ("port", "=", None, 8080_u32)
+/// );
+///
+/// let res = TupleParser::new(tuple_parsers!(StringParser::new("port"), IntegerParser::new()))
+///             .parse("port:8080");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message_stack_trace(),
+///     "[Parser error] Expected `integer` at 1:5\n\nCall Stack:\n2. `integer` parser\n1. `sequence` parser"
+/// );
+/// ```
+#[derive(Clone)]
+pub struct TupleParser<P> {
+    parsers: P,
+    generic_error: String,
+}
+
+impl<P> TupleParser<P> {
+    pub fn new<I: ?Sized>(parsers: P) -> Self
+    where
+        P: TupleParsersT<I>,
+    {
+        let generic_error = parsers.get_generic_error_message();
+
+        TupleParser {
+            parsers,
+            generic_error,
+        }
+    }
+}
+
+impl<P: TupleParsersT<I>, I: ?Sized> ContextParserT<P::Output, I> for TupleParser<P> {
+    fn get_generic_error_message(&self) -> String {
+        self.generic_error.clone()
+    }
+
+    fn get_parser_type(&self) -> ParserType {
+        ParserType::Sequence
+    }
+
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<P::Output, I>, Failure<I>> {
+        self.parsers.parse_from_context(ctx)
+    }
+}
+
+impl<P: TupleParsersT> StringParserT<P::Output> for TupleParser<P> {}
@@ -0,0 +1,87 @@
+use std::{
+    cell::RefCell,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use crate::ParserRc;
+
+/// Generation of the inputs, advanced whenever a `Context` creates a new input. A new input may
+/// reuse the address of a dropped one, so cached indices of older generations are never used.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Address, length and generation of an input
+type InputKey = (usize, usize, u64);
+
+thread_local! {
+    /// Line index of the most recently resolved input, shared by all lookups on the same input. Only
+    /// the key of the input is kept, so the input is freed as soon as it is dropped.
+    static LINE_INDEX: RefCell<Option<(InputKey, ParserRc<LineIndex>)>> = const { RefCell::new(None) };
+}
+
+/// Index of the line starts of an input string, used to resolve byte positions into lines and
+/// columns
+/// ## Example
+/// ```
+/// use microparsec::LineIndex;
+///
+/// let txt = "Hello\nWörld";
+/// let index = LineIndex::new(txt);
+/// assert_eq!(index.line_col(txt, 0), (1, 1));
+/// assert_eq!(index.line_col(txt, 9), (2, 3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    /// Creates a new `LineIndex` of the given text
+    /// * `txt` - The text to index
+    pub fn new(txt: &str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(txt.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+
+        LineIndex { line_starts }
+    }
+
+    /// Returns the 1-based line and column of a byte position, columns are counted in chars.
+    /// Positions past the end of the text are counted as columns of the last line.
+    /// * `txt` - The text that was indexed
+    /// * `pos` - The byte position in the text
+    pub fn line_col(&self, txt: &str, pos: usize) -> (usize, usize) {
+        let line = self.line_starts.partition_point(|start| *start <= pos);
+        let start = self.line_starts[line - 1];
+        let end = pos.min(txt.len());
+
+        let col = txt[start..end].chars().count() + (pos - end) + 1;
+        (line, col)
+    }
+
+    /// Returns the line index of a shared input. The index of the last resolved input is cached,
+    /// so repeated lookups on the same input only build it once.
+    pub(crate) fn of(txt: &ParserRc<str>) -> ParserRc<LineIndex> {
+        let key = (
+            ParserRc::as_ptr(txt).cast::<()>() as usize,
+            txt.len(),
+            GENERATION.load(Ordering::Relaxed),
+        );
+
+        LINE_INDEX.with(|cache| {
+            let mut cache = cache.borrow_mut();
+            match cache.as_ref() {
+                Some((cached_key, index)) if *cached_key == key => index.clone(),
+                _ => {
+                    let index = ParserRc::new(LineIndex::new(txt));
+                    *cache = Some((key, index.clone()));
+                    index
+                }
+            }
+        })
+    }
+
+    /// Advances the generation of the inputs, called whenever a new input is created
+    pub(crate) fn new_generation() {
+        GENERATION.fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    borrow::Borrow,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::ParserRc;
+
+/// Slice of the input of a parse, it shares the input of the `Context` instead of copying the text
+/// and dereferences to `&str`. Use `to_string` to get an owned copy of the text.
+/// ## Example
+/// ```
+/// use microparsec::{LettersParser, ContextParserT, StringParserT};
+///
+/// let res = LettersParser::new().parse("Hello World");
+/// let span = res.unwrap().val;
+/// assert_eq!(span, "Hello");
+/// assert_eq!((span.start, span.end), (0, 5));
+/// assert_eq!(span.to_uppercase(), "HELLO");
+/// ```
+#[derive(Clone)]
+pub struct Span {
+    /// Input the span is a slice of
+    pub txt: ParserRc<str>,
+    /// Byte position of the start of the span in the input
+    pub start: usize,
+    /// Byte position of the end of the span in the input
+    pub end: usize,
+}
+
+impl Span {
+    /// Creates a new `Span`, panics if the positions are not on char boundaries of the input
+    /// * `txt` - The input the span is a slice of
+    /// * `start` - The start of the span
+    /// * `end` - The end of the span
+    pub fn new(txt: ParserRc<str>, start: usize, end: usize) -> Self {
+        assert!(
+            txt.get(start..end).is_some(),
+            "Span must be a valid slice of the input"
+        );
+
+        Span { txt, start, end }
+    }
+
+    /// Returns the text of the span
+    pub fn as_str(&self) -> &str {
+        &self.txt[self.start..self.end]
+    }
+}
+
+impl Deref for Span {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Span {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for Span {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl Debug for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for Span {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Span {}
+
+impl Hash for Span {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl PartialEq<str> for Span {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Span {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for Span {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl From<Span> for String {
+    fn from(span: Span) -> Self {
+        span.as_str().to_string()
+    }
+}
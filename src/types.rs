@@ -1,9 +1,11 @@
-use std::fmt::Display;
+use std::{fmt::Display, ops::Range};
+
+use crate::{ExpectedSet, InputT, LineIndex, TokenT};
 
-#[cfg(not(feature = "thread-safe"))]
-use std::rc::Rc;
 #[cfg(feature = "thread-safe")]
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+#[cfg(not(feature = "thread-safe"))]
+use std::{cell::RefCell, rc::Rc};
 
 #[cfg(not(feature = "thread-safe"))]
 pub type ParserRc<T> = Rc<T>;
@@ -11,13 +13,59 @@ pub type ParserRc<T> = Rc<T>;
 #[cfg(feature = "thread-safe")]
 pub type ParserRc<T> = Arc<T>;
 
+/// Marker trait for closures and values stored inside of parsers. With the `thread-safe` feature
+/// enabled it additionally requires `Send + Sync`.
+#[cfg(not(feature = "thread-safe"))]
+pub trait ParserSyncT {}
+#[cfg(not(feature = "thread-safe"))]
+impl<T: ?Sized> ParserSyncT for T {}
+
+/// Marker trait for closures and values stored inside of parsers. With the `thread-safe` feature
+/// enabled it additionally requires `Send + Sync`.
+#[cfg(feature = "thread-safe")]
+pub trait ParserSyncT: Send + Sync {}
+#[cfg(feature = "thread-safe")]
+impl<T: Send + Sync + ?Sized> ParserSyncT for T {}
+
+/// Interior mutability for parsers that cache state between parses, a `RefCell` or with the
+/// `thread-safe` feature enabled a `Mutex`
+pub(crate) struct ParserMutex<T> {
+    #[cfg(not(feature = "thread-safe"))]
+    inner: RefCell<T>,
+    #[cfg(feature = "thread-safe")]
+    inner: Mutex<T>,
+}
+
+impl<T> ParserMutex<T> {
+    pub(crate) fn new(val: T) -> Self {
+        ParserMutex {
+            #[cfg(not(feature = "thread-safe"))]
+            inner: RefCell::new(val),
+            #[cfg(feature = "thread-safe")]
+            inner: Mutex::new(val),
+        }
+    }
+
+    /// Runs a closure on the mutable value, the value must not be accessed again inside of it
+    pub(crate) fn with<R, F: FnOnce(&mut T) -> R>(&self, f: F) -> R {
+        #[cfg(not(feature = "thread-safe"))]
+        let mut val = self.inner.borrow_mut();
+        #[cfg(feature = "thread-safe")]
+        let mut val = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+
+        f(&mut val)
+    }
+}
+
 #[allow(clippy::too_long_first_doc_paragraph)]
 /// Trait for parsers that can take in a `Context` and act on it. <br>
+/// The input defaults to a string, parsers of other inputs like byte slices set `I` accordingly.
+/// <br>
 /// Parsers are understood to be *pure with static state after initialization*. This is important because
 /// `AnyParser`, `SequenceParser` and likewise parsers store parsers internally as
 /// `ParserRc<dyn ContextParserT<T>>` and thus only make a shallow copy. Parsers that are *not* pure
 /// might and likely will cause unexpected behaviour.
-pub trait ContextParserT<T> {
+pub trait ContextParserT<T, I: ?Sized = str> {
     /// Returns a generic error message of the parser that is configured at initialization and
     /// independent of the runtime result of the attempted parse
     fn get_generic_error_message(&self) -> String;
@@ -26,7 +74,7 @@ pub trait ContextParserT<T> {
     fn get_parser_type(&self) -> ParserType;
 
     /// Consumes a `Context` and attempts to parse it
-    fn parse_from_context(&self, ctx: Context) -> Result<Success<T>, Failure>;
+    fn parse_from_context(&self, ctx: Context<I>) -> Result<Success<T, I>, Failure<I>>;
 }
 
 /// This is a sub-trait of `ContextParserT<T>`. It's only function is to abstract away the creation
@@ -38,22 +86,65 @@ pub trait StringParserT<T>: ContextParserT<T> {
     }
 }
 
+/// This is a sub-trait of `ContextParserT<T, [E]>`, the counterpart of `StringParserT<T>` for
+/// slice inputs like bytes. It is implemented for every parser of a slice input.
+/// ## Example
+/// ```
+/// use microparsec::{BinaryParser, BytesParser, CombinatorT, Endian, Leb128Parser, LengthPrefixedParser, ParserRc, TupleParser, ContextParserT, SliceParserT, tuple_parsers};
+///
+/// // frame := "MP" version:u16be payload:(leb128 length, bytes)
+/// let frame_parser = TupleParser::new(tuple_parsers!(
+///     BytesParser::new(b"MP"),
+///     BinaryParser::<u16>::new(Endian::Big),
+///     LengthPrefixedParser::new(ParserRc::new(Leb128Parser::<u32>::new())),
+/// ))
+/// .map(|res| Ok((res.val.1, res.val.2)));
+///
+/// let res = frame_parser.parse(b"MP\x00\x02\x03abc");
+/// assert_eq!(res.unwrap().val, (2_u16, b"abc".to_vec()));
+///
+/// let res = frame_parser.parse(b"MP\x00");
+/// assert_eq!(
+///     res.unwrap_err().get_error_message(),
+///     "[Parser error] Expected `big endian u16` at byte 2"
+/// );
+/// ```
+pub trait SliceParserT<T, E: Clone>: ContextParserT<T, [E]> {
+    /// Consumes a slice and attempts to parse it
+    fn parse(&self, items: &[E]) -> Result<Success<T, [E]>, Failure<[E]>> {
+        self.parse_from_context(Context::from_slice(items))
+    }
+}
+
+impl<T, E: Clone, P: ContextParserT<T, [E]> + ?Sized> SliceParserT<T, E> for P {}
+
 /// Parser context
-/// * `txt` - input string
-/// * `pos` - current position in input string
-#[derive(Debug, Clone)]
-pub struct Context {
-    /// Current input string
-    pub txt: ParserRc<str>,
-    /// Current position in input string
+/// * `txt` - input, a string by default
+/// * `pos` - current position in input
+#[derive(Debug)]
+pub struct Context<I: ?Sized = str> {
+    /// Current input
+    pub txt: ParserRc<I>,
+    /// Current position in input
     pub pos: usize,
 }
 
+impl<I: ?Sized> Clone for Context<I> {
+    fn clone(&self) -> Self {
+        Context {
+            txt: self.txt.clone(),
+            pos: self.pos,
+        }
+    }
+}
+
 impl Context {
     /// Creates a new Context
     /// * `txt` - The text of the context
     /// * `pos` - The position in the context
     pub fn new<S: AsRef<str>>(txt: S, pos: usize) -> Self {
+        LineIndex::new_generation();
+
         Context {
             txt: txt.as_ref().into(),
             pos,
@@ -63,67 +154,132 @@ impl Context {
     /// Creates a new Context from a text string
     /// * `txt` - The text of the context
     pub fn from<S: AsRef<str>>(txt: S) -> Self {
+        LineIndex::new_generation();
+
         Context {
             txt: txt.as_ref().into(),
             pos: 0,
         }
     }
+
+    /// Returns the 1-based line and column of the current position, columns are counted in chars
+    pub fn line_col(&self) -> (usize, usize) {
+        LineIndex::of(&self.txt).line_col(&self.txt, self.pos)
+    }
+}
+
+impl<E: Clone> Context<[E]> {
+    /// Creates a new Context from a slice
+    /// * `items` - The slice of the context
+    pub fn from_slice(items: &[E]) -> Self {
+        Context {
+            txt: items.into(),
+            pos: 0,
+        }
+    }
+}
+
+impl<T: TokenT> Context<[T]> {
+    /// Returns the source span of the token at the current position, if it provides one
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.txt.get(self.pos).and_then(|token| token.span())
+    }
+}
+
+impl<I: InputT + ?Sized> Context<I> {
+    /// Returns a human readable description of the current position, see `InputT::location`
+    pub fn location(&self) -> String {
+        I::location(&self.txt, self.pos)
+    }
 }
 
 /// `Success` is a successful parse result
 /// * `val` holds the value of the parse
 /// * `ctx` holds the context of the parse
-#[derive(Debug, Clone)]
-pub struct Success<T> {
+#[derive(Debug)]
+pub struct Success<T, I: ?Sized = str> {
     /// Value of the parse
     pub val: T,
     /// Context of the parse
-    pub ctx: Context,
+    pub ctx: Context<I>,
 }
 
-impl<T> Success<T> {
+impl<T, I: ?Sized> Success<T, I> {
     /// Creates a new `Success` object with the given value and context
     /// * `ctx` - the parse context
     /// * `val` - the parsed value
-    pub fn new(val: T, ctx: Context) -> Success<T> {
+    pub fn new(val: T, ctx: Context<I>) -> Success<T, I> {
         Success { val, ctx }
     }
 }
 
+impl<T: Clone, I: ?Sized> Clone for Success<T, I> {
+    fn clone(&self) -> Self {
+        Success {
+            val: self.val.clone(),
+            ctx: self.ctx.clone(),
+        }
+    }
+}
+
 /// `Failure` is a failed parse result
-/// * `exp` holds the error message
+/// * `exp` holds the set of expectations
 /// * `ctx` holds the context of the parse
 /// * `p_type_stack` holds a call stack of parsers that lead up to the failure
-#[derive(Debug, Clone)]
-pub struct Failure {
-    /// Error message
-    pub exp: String,
+#[derive(Debug)]
+pub struct Failure<I: ?Sized = str> {
+    /// Expectations that were not met
+    pub exp: ExpectedSet,
     /// Context of the parse
-    pub ctx: Context,
+    pub ctx: Context<I>,
 
     /// Stack of parsers
     pub p_type_stack: Vec<ParserType>,
 }
 
-impl Failure {
-    /// Creates a new `Failure` object with a short error message and context
+impl<I: ?Sized> Failure<I> {
+    /// Creates a new `Failure` object with the expectations and context
     /// * `ctx` - the parse context
-    /// * `exp` - a string of what was expected
+    /// * `exp` - what was expected, strings are treated as custom labels
     /// * `p_type_stack` - the parser stack that caused the failure
-    pub fn new<S: AsRef<str>>(exp: S, ctx: Context, p_type_stack: Vec<ParserType>) -> Failure {
-        let exp = exp.as_ref().to_string();
+    pub fn new<E: Into<ExpectedSet>>(
+        exp: E,
+        ctx: Context<I>,
+        p_type_stack: Vec<ParserType>,
+    ) -> Failure<I> {
+        let exp = exp.into();
         Failure {
             exp,
             ctx,
             p_type_stack,
         }
     }
+}
+
+impl<I: ?Sized> Clone for Failure<I> {
+    fn clone(&self) -> Self {
+        Failure {
+            exp: self.exp.clone(),
+            ctx: self.ctx.clone(),
+            p_type_stack: self.p_type_stack.clone(),
+        }
+    }
+}
 
+impl<I: InputT + ?Sized> Failure<I> {
     /// Returns a human readable error message of the failure
+    /// ## Example
+    /// ```
+    /// use microparsec::{Context, StringParser, ContextParserT};
+    ///
+    /// let res = StringParser::new("Hello").parse_from_context(Context::new("Hi\nHallo", 3));
+    /// assert_eq!(res.unwrap_err().get_error_message(), "[Parser error] Expected `Hello` at 2:1");
+    /// ```
     pub fn get_error_message(&self) -> String {
         format!(
-            "[Parser error] Expected `{}` at position: {}",
-            self.exp, self.ctx.pos,
+            "[Parser error] Expected {} at {}",
+            self.exp,
+            self.ctx.location()
         )
     }
 
@@ -138,12 +294,27 @@ impl Failure {
             .collect::<Vec<String>>()
             .join("\n");
         format!(
-            "[Parser error] Expected `{}` at position: {}\n\nCall Stack:\n{call_stack}",
-            self.exp, self.ctx.pos,
+            "[Parser error] Expected {} at {}\n\nCall Stack:\n{call_stack}",
+            self.exp,
+            self.ctx.location()
         )
     }
 }
 
+/// Enum used to determine how the `string` parser compares its target with the input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Matching {
+    /// The input has to be byte for byte equal to the target
+    Exact,
+    /// ASCII letters are compared case-insensitively, e.g. `SELECT` matches `select`
+    IgnoreAsciiCase,
+    /// Letters are compared under the full Unicode case mappings, e.g. `STRASSE` matches `straße`
+    IgnoreCase,
+    /// The input and the target are compared under NFC normalization, so composed and decomposed
+    /// accents are equal
+    Nfc,
+}
+
 /// Enum used to determine the *relative* position to parse to in the `exact` parser
 #[derive(Clone, Copy)]
 pub enum Pos {
@@ -153,26 +324,99 @@ pub enum Pos {
     EOI,
 }
 
+/// Enum used to determine how a trailing separator is handled in the `sep_by` parsers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Trailing {
+    /// A separator must be followed by another value
+    Forbidden,
+    /// The last value may be followed by a separator
+    Optional,
+    /// Every value must be followed by a separator
+    Required,
+}
+
+/// Enum used to determine whether `_` digit separators are accepted in the `int` parser
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Separators {
+    /// Digits must not be separated
+    Forbidden,
+    /// Single `_` may be placed between two digits
+    Allowed,
+}
+
+/// Enum used to determine the accepted syntax of the `float` parser
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatSyntax {
+    /// Numbers as defined by JSON, e.g. `-0.5e10`
+    Json,
+    /// Float literals as defined by Rust with an optional `-`, e.g. `1_000.5` or `1e-3`
+    Rust,
+    /// Anything accepted by `str::parse`, e.g. `+.5`, `5.`, `inf` or `NaN`
+    Permissive,
+}
+
+/// Enum used to determine the byte order in the `binary` parser
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Endian {
+    /// Most significant byte first
+    Big,
+    /// Least significant byte first
+    Little,
+}
+
+impl Display for Endian {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Endian::Big => write!(f, "big endian"),
+            Endian::Little => write!(f, "little endian"),
+        }
+    }
+}
+
 /// The types of parsers
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParserType {
     Any,
     Between,
+    Binary,
+    Byte,
+    Bytes,
+    ChainLeft,
+    ChainRight,
+    Count,
     Exact,
     Expect,
+    Expression,
     Float,
     Forget,
     Integer,
+    Leb128,
+    LeftRecursive,
+    LengthPrefixed,
     Letters,
     Many,
+    ManyTill,
     Map,
+    Memo,
+    NoneOf,
     Not,
+    NotFollowedBy,
+    OneOf,
     Optional,
+    Peek,
+    Recursive,
     Regex,
+    Repeat,
+    Satisfy,
+    SepBy,
+    SepBy1,
     Sequence,
     Spaces,
     String,
+    StringLiteral,
     Surely,
+    TakeWhile,
+    Token,
 
     /// Custom parsers type can be denoted with a custom type
     Custom(String),
@@ -183,21 +427,45 @@ impl Display for ParserType {
         let str = match self {
             ParserType::Any => "any",
             ParserType::Between => "between",
+            ParserType::Binary => "binary",
+            ParserType::Byte => "byte",
+            ParserType::Bytes => "bytes",
+            ParserType::ChainLeft => "chain left",
+            ParserType::ChainRight => "chain right",
+            ParserType::Count => "count",
             ParserType::Exact => "exact",
             ParserType::Expect => "expect",
+            ParserType::Expression => "expression",
             ParserType::Float => "float",
             ParserType::Forget => "forget",
             ParserType::Integer => "integer",
+            ParserType::Leb128 => "leb128",
+            ParserType::LeftRecursive => "left recursive",
+            ParserType::LengthPrefixed => "length prefixed",
             ParserType::Letters => "letters",
             ParserType::Many => "many",
+            ParserType::ManyTill => "many till",
             ParserType::Map => "map",
+            ParserType::Memo => "memo",
+            ParserType::NoneOf => "none of",
             ParserType::Not => "not",
+            ParserType::NotFollowedBy => "not followed by",
+            ParserType::OneOf => "one of",
             ParserType::Optional => "optional",
+            ParserType::Peek => "peek",
+            ParserType::Recursive => "recursive",
             ParserType::Regex => "regex",
+            ParserType::Repeat => "repeat",
+            ParserType::Satisfy => "satisfy",
+            ParserType::SepBy => "sep by",
+            ParserType::SepBy1 => "sep by 1",
             ParserType::Sequence => "sequence",
             ParserType::Spaces => "spaces",
             ParserType::String => "string",
+            ParserType::StringLiteral => "string literal",
             ParserType::Surely => "surely",
+            ParserType::TakeWhile => "take while",
+            ParserType::Token => "token",
             ParserType::Custom(parser) => parser.as_ref(),
         };
 
@@ -0,0 +1,167 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod any {
+    use microparsec::{
+        AnyParser, CombinatorT, Context, ContextParserT, Expected, ExpectedSet, Failure,
+        ParserType, SequenceParser, Span, StringParser, StringParserT, SurelyParser,
+    };
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_rand_string, __get_seeded_rng};
+
+    /// Parses the target character by character, so it fails at the first differing character
+    fn alternative(target: &str) -> SequenceParser<Span> {
+        SequenceParser::new(
+            target
+                .chars()
+                .map(|c| StringParser::new(c.to_string()).rc())
+                .collect(),
+        )
+    }
+
+    fn common_prefix(a: &str, b: &str) -> usize {
+        a.chars().zip(b.chars()).take_while(|(a, b)| a == b).count()
+    }
+
+    fn furthest(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        let n = rng.gen_range(1..6);
+        let targets: Vec<String> = (0..n)
+            .map(|_| {
+                let len = rng.gen_range(1..5);
+                __get_rand_string(rng, len)
+            })
+            .collect();
+        let txt = __get_rand_string(rng, 4);
+
+        let parser = AnyParser::new(targets.iter().map(|t| alternative(t).rc()).collect());
+        let res = parser.parse(&txt);
+
+        // the first alternative that matches completely wins
+        if let Some(target) = targets.iter().find(|t| txt.starts_with(t.as_str())) {
+            let res = res.unwrap();
+            assert_eq!(
+                res.val.iter().map(|s| s.to_string()).collect::<String>(),
+                *target,
+                "Failed i={i}, x={x}, seed={seed}"
+            );
+            assert_eq!(
+                res.ctx.pos,
+                target.len(),
+                "Failed i={i}, x={x}, seed={seed}"
+            );
+            return;
+        }
+
+        // the alternatives that got the furthest are merged in order and without duplicates
+        let pos = targets
+            .iter()
+            .map(|t| common_prefix(t, &txt))
+            .max()
+            .unwrap();
+        let deepest: Vec<&String> = targets
+            .iter()
+            .filter(|t| common_prefix(t, &txt) == pos)
+            .collect();
+        let mut expected = ExpectedSet::new();
+        deepest
+            .iter()
+            .for_each(|t| expected.insert(Expected::Literal(t[pos..pos + 1].to_string())));
+
+        let err = res.unwrap_err();
+        assert_eq!(
+            err.get_error_message(),
+            Failure::new(expected, Context::new("", pos), vec![]).get_error_message(),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+
+        // a single deepest failure keeps its stack, merged failures only report the any parser
+        let stack = match deepest.len() {
+            1 => vec![ParserType::String, ParserType::Sequence, ParserType::Any],
+            _ => vec![ParserType::Any],
+        };
+        assert_eq!(err.p_type_stack, stack, "Failed i={i}, x={x}, seed={seed}");
+    }
+
+    fn surely(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        let n = rng.gen_range(2..6);
+        let targets: Vec<String> = (0..n)
+            .map(|_| {
+                let len = rng.gen_range(1..5);
+                __get_rand_string(rng, len)
+            })
+            .collect();
+        let txt = __get_rand_string(rng, 4);
+
+        // a random alternative is wrapped in a surely parser
+        let k = rng.gen_range(0..n);
+        let parser = AnyParser::new(
+            targets
+                .iter()
+                .enumerate()
+                .map(|(j, t)| match j == k {
+                    true => SurelyParser::new(alternative(t).rc()).rc(),
+                    false => alternative(t).rc(),
+                })
+                .collect(),
+        );
+        let res = parser.parse(&txt);
+
+        let first = targets.iter().position(|t| txt.starts_with(t.as_str()));
+        match first {
+            // an earlier alternative matched or the surely parser matched itself
+            Some(j) if j <= k => assert_eq!(
+                res.unwrap().ctx.pos,
+                targets[j].len(),
+                "Failed i={i}, y={y}, seed={seed}"
+            ),
+            // the surely parser failed, later alternatives are never tried
+            _ => {
+                let err = res.unwrap_err();
+                let surely = format!(
+                    "surely `{}`",
+                    alternative(&targets[k]).get_generic_error_message()
+                );
+                assert_eq!(
+                    err.get_error_message(),
+                    Failure::new(
+                        surely.as_str(),
+                        Context::new("", common_prefix(&targets[k], &txt)),
+                        vec![]
+                    )
+                    .get_error_message(),
+                    "Failed i={i}, y={y}, seed={seed}"
+                );
+                assert_eq!(
+                    err.p_type_stack,
+                    vec![
+                        ParserType::String,
+                        ParserType::Sequence,
+                        ParserType::Surely,
+                        ParserType::Any
+                    ],
+                    "Failed i={i}, y={y}, seed={seed}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of alternatives failing at different positions
+            for x in 0..50 {
+                furthest(seed, &mut rng, i, x);
+            }
+
+            // 50 iterations of alternatives with a surely parser
+            for y in 0..50 {
+                surely(seed, &mut rng, i, y);
+            }
+        }
+    }
+}
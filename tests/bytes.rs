@@ -0,0 +1,131 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod bytes {
+    use microparsec::{BinaryParser, Endian, Leb128Parser, SliceParserT};
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::__get_seeded_rng;
+
+    fn leb128_unsigned(mut val: u64) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        loop {
+            let byte = (val & 0x7f) as u8;
+            val >>= 7;
+            if val == 0 {
+                bytes.push(byte);
+                return bytes;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    fn leb128_signed(mut val: i64) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        loop {
+            let byte = (val & 0x7f) as u8;
+            val >>= 7;
+            if (val == 0 && byte & 0x40 == 0) || (val == -1 && byte & 0x40 != 0) {
+                bytes.push(byte);
+                return bytes;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    fn binary(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        // encode a random number in a random byte order followed by random bytes
+        let val: u64 = rng.gen();
+        let endian = if rng.gen() {
+            Endian::Big
+        } else {
+            Endian::Little
+        };
+        let mut bytes = match endian {
+            Endian::Big => val.to_be_bytes().to_vec(),
+            Endian::Little => val.to_le_bytes().to_vec(),
+        };
+        let rest = rng.gen_range(0..8);
+        bytes.extend((0..rest).map(|_| rng.gen::<u8>()));
+
+        let res = BinaryParser::<u64>::new(endian).parse(&bytes);
+        assert_eq!(
+            res.clone().unwrap().val,
+            val,
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+        assert_eq!(res.unwrap().ctx.pos, 8, "Failed i={i}, x={x}, seed={seed}");
+
+        // truncated input
+        let len = rng.gen_range(0..8);
+        let res = BinaryParser::<u64>::new(endian).parse(&bytes[..len]);
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            format!("[Parser error] Expected `{endian} u64` at byte 0"),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+    }
+
+    fn leb128(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        // random magnitudes so short and long varints are covered
+        let shift = rng.gen_range(0..64);
+        let val = rng.gen::<u64>() >> shift;
+        let bytes = leb128_unsigned(val);
+
+        let res = Leb128Parser::<u64>::new().parse(&bytes);
+        assert_eq!(
+            res.clone().unwrap().val,
+            val,
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            bytes.len(),
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+
+        let res = Leb128Parser::<u32>::new().parse(&bytes);
+        match u32::try_from(val) {
+            Ok(val) => assert_eq!(res.unwrap().val, val, "Failed i={i}, y={y}, seed={seed}"),
+            Err(_) => assert_eq!(
+                res.unwrap_err().get_error_message(),
+                "[Parser error] Expected `leb128 u32 (overflow)` at byte 0",
+                "Failed i={i}, y={y}, seed={seed}"
+            ),
+        }
+
+        let val = (rng.gen::<u64>() as i64) >> shift;
+        let bytes = leb128_signed(val);
+
+        let res = Leb128Parser::<i64>::new().parse(&bytes);
+        assert_eq!(
+            res.clone().unwrap().val,
+            val,
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            bytes.len(),
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of fixed size numbers
+            for x in 0..50 {
+                binary(seed, &mut rng, i, x);
+            }
+
+            // 50 iterations of varints
+            for y in 0..50 {
+                leb128(seed, &mut rng, i, y);
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod char_class {
+    use std::ops::Not;
+
+    use microparsec::{
+        Context, ContextParserT, Expected, ExpectedSet, NoneOfParser, OneOfParser, SatisfyParser,
+        StringParserT, TakeWhileParser,
+    };
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_rand_string, __get_seeded_rng};
+
+    fn one_of(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        let set = __get_rand_string(rng, 3);
+        let txt = __get_rand_string(rng, 3);
+        let first = txt.chars().next().unwrap();
+
+        let res = OneOfParser::new(&set).parse(&txt);
+        assert_eq!(
+            res.is_ok(),
+            set.contains(first),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+
+        let res = NoneOfParser::new(&set).parse(&txt);
+        match res {
+            Ok(res) => {
+                assert_eq!(res.val, first, "Failed i={i}, x={x}, seed={seed}");
+                assert!(
+                    set.contains(first).not(),
+                    "Failed i={i}, x={x}, seed={seed}"
+                );
+            }
+            Err(err) => assert_eq!(
+                err.get_error_message(),
+                format!("[Parser error] Expected `none of [{set}]` at 1:1"),
+                "Failed i={i}, x={x}, seed={seed}"
+            ),
+        }
+    }
+
+    fn take_while(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        let n = rng.gen_range(0..20);
+        let txt = __get_rand_string(rng, n);
+        let min = rng.gen_range(0..5);
+        let max = rng.gen_range(min + 1..10);
+
+        // the set of accepted characters is a random prefix of the alphabet
+        let last = rng.gen_range(b'a'..=b'f') as char;
+        let parser = TakeWhileParser::new(move |c| c <= last, min..=max, "char");
+
+        let count = txt.chars().take_while(|&c| c <= last).count();
+        let res = parser.parse(&txt);
+        if count < min {
+            assert_eq!(
+                res.unwrap_err().get_error_message(),
+                format!(
+                    "[Parser error] Expected `between {min} and {max} `char` (found {count})` at 1:{}",
+                    count + 1
+                ),
+                "Failed i={i}, y={y}, seed={seed}"
+            );
+        } else {
+            assert_eq!(
+                res.unwrap().val,
+                txt[..count.min(max)],
+                "Failed i={i}, y={y}, seed={seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_position() {
+        // past the end of input and inside of a multi-byte character
+        for pos in [4, 6, 10] {
+            let ctx = Context::new("abcä", pos);
+            let expected = ExpectedSet::from(Expected::EndOfInput);
+
+            let res = SatisfyParser::new(|_| true, "any").parse_from_context(ctx.clone());
+            assert_eq!(res.unwrap_err().exp, expected, "Failed pos={pos}");
+            let res = OneOfParser::new("ä").parse_from_context(ctx.clone());
+            assert_eq!(res.unwrap_err().exp, expected, "Failed pos={pos}");
+            let res = NoneOfParser::new("a").parse_from_context(ctx.clone());
+            assert_eq!(res.unwrap_err().exp, expected, "Failed pos={pos}");
+            let res = TakeWhileParser::new(|_| true, .., "any").parse_from_context(ctx);
+            assert_eq!(res.unwrap_err().exp, expected, "Failed pos={pos}");
+        }
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of character sets
+            for x in 0..50 {
+                one_of(seed, &mut rng, i, x);
+            }
+
+            // 50 iterations of bounded predicates
+            for y in 0..50 {
+                take_while(seed, &mut rng, i, y);
+            }
+        }
+    }
+}
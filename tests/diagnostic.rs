@@ -0,0 +1,113 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod diagnostic {
+    use microparsec::{Context, DiagnosticStyle, Failure, ParserType};
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_rand_string, __get_seeded_rng};
+
+    /// Removes the ANSI escape codes of a diagnostic
+    fn strip_ansi(txt: &str) -> String {
+        let mut ret = String::new();
+        let mut chars = txt.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\x1b' => {
+                    chars.by_ref().find(|c| *c == 'm');
+                }
+                c => ret.push(c),
+            }
+        }
+        ret
+    }
+
+    fn diagnostic(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        // random lines with tabs
+        let n = rng.gen_range(1..5);
+        let lines: Vec<String> = (0..n)
+            .map(|_| {
+                let len = rng.gen_range(0..10);
+                __get_rand_string(rng, len)
+                    .chars()
+                    .map(|c| if c == 'f' { '\t' } else { c })
+                    .collect()
+            })
+            .collect();
+        let txt = lines.join("\n");
+
+        let pos = rng.gen_range(0..=txt.len());
+        let start = rng.gen_range(0..=pos);
+        let err = Failure::new(
+            "x",
+            Context::new(&txt, pos),
+            vec![ParserType::String, ParserType::Sequence],
+        );
+
+        // the underline starts at the beginning of the failure line at the earliest
+        let line = txt[..pos].matches('\n').count() + 1;
+        let line_start = txt[..pos].rfind('\n').map_or(0, |i| i + 1);
+        let col = pos - line_start + 1;
+        let from = start.max(line_start);
+        let width = |s: &str| {
+            s.chars()
+                .map(|c| if c == '\t' { 4 } else { 1 })
+                .sum::<usize>()
+        };
+        let padding = " ".repeat(width(&txt[line_start..from]));
+        let underline = "-".repeat(width(&txt[from..pos]));
+        let source = lines[line - 1].replace('\t', "    ");
+        let gutter = " ".repeat(line.to_string().len());
+
+        let expected = [
+            "error: expected `x`".to_string(),
+            format!("{gutter}--> {line}:{col}"),
+            format!("{gutter} |"),
+            format!("{line} | {source}"),
+            format!("{gutter} | {padding}{underline}^"),
+            format!("{gutter} |"),
+            format!("{gutter} = expected: `x`"),
+            format!("{gutter} = call stack:"),
+            format!("{gutter}     2. `string` parser"),
+            format!("{gutter}     1. `sequence` parser"),
+        ]
+        .join("\n");
+
+        let plain = err.get_error_diagnostic_from(start, DiagnosticStyle::Plain);
+        assert_eq!(plain, expected, "Failed i={i}, x={x}, seed={seed}");
+
+        // the colored diagnostic only differs in its escape codes
+        let ansi = err.get_error_diagnostic_from(start, DiagnosticStyle::Ansi);
+        assert_eq!(
+            strip_ansi(&ansi),
+            expected,
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+        assert!(
+            ansi.contains(&format!("\x1b[1;31m{underline}^\x1b[0m")),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+
+        // without a start only the caret is shown
+        let caret = err.get_error_diagnostic(DiagnosticStyle::Plain);
+        assert_eq!(
+            caret.lines().nth(4).unwrap(),
+            format!("{gutter} | {}^", " ".repeat(width(&txt[line_start..pos]))),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of random failure positions
+            for x in 0..50 {
+                diagnostic(seed, &mut rng, i, x);
+            }
+        }
+    }
+}
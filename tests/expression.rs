@@ -0,0 +1,216 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod expression {
+    use microparsec::{
+        CombinatorT, ExpressionParser, Fixity, OneOfParser, Operation, Operator, StringParser,
+        StringParserT,
+    };
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+    use crate::utils::{__get_error_message, __get_seeded_rng};
+
+    type Parser = ExpressionParser<String, String>;
+
+    fn operator(symbol: &str, fixity: Fixity, binding_power: u32) -> Operator<String> {
+        let parser = StringParser::new(symbol)
+            .map(|res| Ok(res.val.to_string()))
+            .expect(symbol);
+        Operator::new(parser.rc(), fixity, binding_power)
+    }
+
+    /// Parses expressions into fully parenthesized strings, `<` is listed in front of `<<` on
+    /// purpose
+    fn parser() -> Parser {
+        let atom = OneOfParser::new("abcdef").map(|res| Ok(res.val.to_string()));
+        let operators = vec![
+            operator("-", Fixity::InfixLeft, 1),
+            operator("<", Fixity::InfixLeft, 1),
+            operator("<<", Fixity::InfixLeft, 3),
+            operator("^", Fixity::InfixRight, 5),
+            operator("~", Fixity::Prefix, 7),
+            operator("!", Fixity::Postfix, 8),
+            operator("?", Fixity::Postfix, 0),
+        ];
+
+        ExpressionParser::new(atom.rc(), operators, fold)
+    }
+
+    /// Folds an operation into a parenthesized string
+    fn fold(operation: Operation<String, String>) -> String {
+        match operation {
+            Operation::Prefix(op, x) => format!("({op}{x})"),
+            Operation::Infix(a, op, b) => format!("({a}{op}{b})"),
+            Operation::Postfix(x, op) => format!("({x}{op})"),
+        }
+    }
+
+    /// Returns a random term with unary operators and its parenthesization
+    fn term(rng: &mut StdRng) -> (String, String) {
+        let atom = ['a', 'b', 'c', 'd', 'e', 'f']
+            .choose(rng)
+            .unwrap()
+            .to_string();
+
+        // `!` binds tighter than `~`
+        let (txt, expected) = match rng.gen_bool(0.3) {
+            true => (format!("{atom}!"), format!("({atom}!)")),
+            false => (atom.clone(), atom),
+        };
+        match rng.gen_bool(0.3) {
+            true => (format!("~{txt}"), format!("(~{expected})")),
+            false => (txt, expected),
+        }
+    }
+
+    /// Returns a random expression of left-associative operators and its parenthesization
+    fn left_expression(rng: &mut StdRng) -> (String, String) {
+        let n = rng.gen_range(1..8);
+        let (mut txt, mut group) = term(rng);
+
+        // runs of `<<` are folded first, the runs are folded by `-` and `<` afterwards
+        let mut groups = vec![];
+        for _ in 1..n {
+            let op = *["-", "<", "<<"].choose(rng).unwrap();
+            let (t, e) = term(rng);
+            txt.push_str(op);
+            txt.push_str(&t);
+            match op {
+                "<<" => group = format!("({group}<<{e})"),
+                _ => groups.push((op, std::mem::replace(&mut group, e))),
+            }
+        }
+        groups.push(("", group));
+
+        let mut groups = groups.into_iter();
+        let (mut op, mut expected) = groups.next().unwrap();
+        for (next, group) in groups {
+            expected = format!("({expected}{op}{group})");
+            op = next;
+        }
+
+        // `?` binds the loosest and applies to the whole expression
+        match rng.gen_bool(0.2) {
+            true => (format!("{txt}?"), format!("({expected}?)")),
+            false => (txt, expected),
+        }
+    }
+
+    fn left(seed: u64, rng: &mut StdRng, parser: &Parser, i: u32, x: u32) {
+        let (txt, expected) = left_expression(rng);
+
+        let res = parser.parse(&txt);
+        assert_eq!(
+            res.clone().unwrap().val,
+            expected,
+            "Failed i={i}, x={x}, seed={seed}, txt={txt}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            txt.len(),
+            "Failed i={i}, x={x}, seed={seed}, txt={txt}"
+        );
+    }
+
+    fn right(seed: u64, rng: &mut StdRng, parser: &Parser, i: u32, y: u32) {
+        let n = rng.gen_range(1..8);
+        let terms: Vec<(String, String)> = (0..n).map(|_| term(rng)).collect();
+
+        let txt = terms
+            .iter()
+            .map(|(t, _)| t.as_str())
+            .collect::<Vec<&str>>()
+            .join("^");
+        let mut expected = terms.last().unwrap().1.clone();
+        for (_, e) in terms.iter().rev().skip(1) {
+            expected = format!("({e}^{expected})");
+        }
+
+        let res = parser.parse(&txt);
+        assert_eq!(
+            res.clone().unwrap().val,
+            expected,
+            "Failed i={i}, y={y}, seed={seed}, txt={txt}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            txt.len(),
+            "Failed i={i}, y={y}, seed={seed}, txt={txt}"
+        );
+    }
+
+    fn dangling(seed: u64, rng: &mut StdRng, parser: &Parser, i: u32, z: u32) {
+        let (txt, _) = left_expression(rng);
+        let txt = txt.trim_end_matches('?');
+
+        // an operator without an operand fails right behind the operator
+        let (op, symbol) = *[
+            ("-", "-"),
+            ("<", "<"),
+            ("<<", "<<"),
+            ("^", "^"),
+            ("-~", "~"),
+        ]
+        .choose(rng)
+        .unwrap();
+        let tail = ["", "+", "?"].choose(rng).unwrap();
+        let res = parser.parse(format!("{txt}{op}{tail}"));
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message(&format!("operand after `{symbol}`"), txt.len() + op.len()),
+            "Failed i={i}, z={z}, seed={seed}, txt={txt}{op}{tail}"
+        );
+    }
+
+    #[test]
+    fn max_binding_power() {
+        // only left-associative operators need a higher binding power for their right operand
+        let parser = Parser::new(
+            OneOfParser::new("ab")
+                .map(|res| Ok(res.val.to_string()))
+                .rc(),
+            vec![
+                operator("^", Fixity::InfixRight, u32::MAX),
+                operator("-", Fixity::Prefix, u32::MAX),
+                operator("+", Fixity::InfixLeft, u32::MAX - 1),
+            ],
+            fold,
+        );
+        assert_eq!(parser.parse("a^-b^a+b").unwrap().val, "((a^(-(b^a)))+b)");
+    }
+
+    #[test]
+    #[should_panic(expected = "Binding power of a left-associative operator must be less than")]
+    fn max_binding_power_left() {
+        operator("+", Fixity::InfixLeft, u32::MAX);
+    }
+
+    #[test]
+    fn test() {
+        let parser = parser();
+
+        assert_eq!(parser.parse("a<<b").unwrap().val, "(a<<b)");
+        assert_eq!(parser.parse("a<b<<c").unwrap().val, "(a<(b<<c))");
+
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of left-associative operators
+            for x in 0..50 {
+                left(seed, &mut rng, &parser, i, x);
+            }
+
+            // 50 iterations of right-associative operators
+            for y in 0..50 {
+                right(seed, &mut rng, &parser, i, y);
+            }
+
+            // 50 iterations of operators without an operand
+            for z in 0..50 {
+                dangling(seed, &mut rng, &parser, i, z);
+            }
+        }
+    }
+}
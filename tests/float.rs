@@ -0,0 +1,121 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod float {
+    use microparsec::{FloatParser, FloatSyntax, StringParserT};
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::__get_seeded_rng;
+
+    const SYNTAXES: [FloatSyntax; 3] = [
+        FloatSyntax::Json,
+        FloatSyntax::Rust,
+        FloatSyntax::Permissive,
+    ];
+
+    fn round_trip(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        // random bit patterns cover subnormal, small and huge magnitudes
+        let val = loop {
+            let val = f64::from_bits(rng.gen());
+            if val.is_finite() {
+                break val;
+            }
+        };
+
+        // the shortest representation has to parse back to the exact same value
+        let txt = if rng.gen() {
+            format!("{val:?}")
+        } else {
+            format!("{val:e}")
+        };
+
+        for syntax in SYNTAXES {
+            let res = FloatParser::<f64>::new(syntax).parse(format!("{txt},"));
+            assert_eq!(
+                res.clone().unwrap().val.to_bits(),
+                val.to_bits(),
+                "Failed i={i}, x={x}, seed={seed}, txt={txt}, syntax={syntax:?}"
+            );
+            assert_eq!(
+                res.unwrap().ctx.pos,
+                txt.len(),
+                "Failed i={i}, x={x}, seed={seed}, txt={txt}, syntax={syntax:?}"
+            );
+        }
+
+        let val = val as f32;
+        if val.is_finite() {
+            let txt = format!("{val:?}");
+            let res = FloatParser::<f32>::new(FloatSyntax::Json).parse(&txt);
+            assert_eq!(
+                res.unwrap().val.to_bits(),
+                val.to_bits(),
+                "Failed i={i}, x={x}, seed={seed}, txt={txt}"
+            );
+        }
+    }
+
+    fn syntax(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        // (text, accepted length for json, rust and permissive, 0 if out of range)
+        let cases = [
+            ("1", [Some(1), None, Some(1)]),
+            ("-1.5", [Some(4), Some(4), Some(4)]),
+            ("+1.5", [None, None, Some(4)]),
+            (".5", [None, None, Some(2)]),
+            ("5.", [Some(1), Some(2), Some(2)]),
+            ("5.x", [Some(1), None, Some(2)]),
+            ("01.5", [Some(1), Some(4), Some(4)]),
+            ("1e10", [Some(4), Some(4), Some(4)]),
+            ("1.5E-3", [Some(6), Some(6), Some(6)]),
+            ("1e", [Some(1), None, Some(1)]),
+            ("1_0.5", [Some(1), Some(5), Some(1)]),
+            ("inf", [None, None, Some(3)]),
+            ("-Infinity", [None, None, Some(9)]),
+            ("NaN", [None, None, Some(3)]),
+            ("1e400", [Some(0), Some(0), Some(5)]),
+            ("-1_0e400", [Some(2), Some(0), Some(2)]),
+        ];
+
+        let (txt, lens) = cases[rng.gen_range(0..cases.len())];
+        for (syntax, len) in SYNTAXES.into_iter().zip(lens) {
+            let res = FloatParser::<f64>::new(syntax).parse(txt);
+            match len {
+                // out of range literals fail at their start
+                Some(0) => assert_eq!(
+                    res.unwrap_err().get_error_message(),
+                    "[Parser error] Expected `float (out of range)` at 1:1",
+                    "Failed i={i}, y={y}, seed={seed}, txt={txt}, syntax={syntax:?}"
+                ),
+                Some(len) => assert_eq!(
+                    res.unwrap().ctx.pos,
+                    len,
+                    "Failed i={i}, y={y}, seed={seed}, txt={txt}, syntax={syntax:?}"
+                ),
+                None => assert_eq!(
+                    res.unwrap_err().get_error_message(),
+                    "[Parser error] Expected `float` at 1:1",
+                    "Failed i={i}, y={y}, seed={seed}, txt={txt}, syntax={syntax:?}"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of random floats
+            for x in 0..50 {
+                round_trip(seed, &mut rng, i, x);
+            }
+
+            // 10 iterations of syntax variants
+            for y in 0..10 {
+                syntax(seed, &mut rng, i, y);
+            }
+        }
+    }
+}
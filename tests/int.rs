@@ -0,0 +1,109 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod int {
+    use microparsec::{IntParser, Separators, StringParserT};
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::__get_seeded_rng;
+
+    /// Formats the magnitude in a random radix with its prefix, returns the text and the radix
+    fn format_radix(rng: &mut StdRng, val: u64) -> (String, u32) {
+        match rng.gen_range(0..4) {
+            0 => (format!("0x{val:x}"), 16),
+            1 => (format!("0o{val:o}"), 8),
+            2 => (format!("0b{val:b}"), 2),
+            _ => (format!("{val}"), 10),
+        }
+    }
+
+    /// Inserts `_` between random digits, never after the prefix
+    fn separate(rng: &mut StdRng, txt: &str, radix: u32) -> String {
+        let prefix = if radix == 10 { 0 } else { 2 };
+        let mut out = txt[..prefix].to_string();
+        for (i, c) in txt[prefix..].chars().enumerate() {
+            if i > 0 && rng.gen_bool(0.2) {
+                out.push('_');
+            }
+            out.push(c);
+        }
+        out
+    }
+
+    fn round_trip(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        // random magnitudes so short and long numbers are covered
+        let shift = rng.gen_range(0..64);
+        let val = (rng.gen::<u64>() as i64) >> shift;
+
+        let (digits, radix) = format_radix(rng, val.unsigned_abs());
+        let sign = if val < 0 { "-" } else { "" };
+        let txt = format!("{sign}{digits};");
+
+        let res = IntParser::<i64>::new(Separators::Forbidden).parse(&txt);
+        assert_eq!(
+            res.clone().unwrap().val,
+            val,
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            txt.len() - 1,
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+
+        let separated = format!("{sign}{}", separate(rng, &digits, radix));
+        let res = IntParser::<i64>::new(Separators::Allowed).parse(&separated);
+        assert_eq!(
+            res.clone().unwrap().val,
+            val,
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            separated.len(),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+    }
+
+    fn overflow(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        let shift = rng.gen_range(0..48);
+        let val = rng.gen::<u64>() >> shift;
+
+        let (txt, radix) = format_radix(rng, val);
+        let res = IntParser::<u16>::new(Separators::Forbidden).parse(&txt);
+        match u16::try_from(val) {
+            Ok(val) => assert_eq!(res.unwrap().val, val, "Failed i={i}, y={y}, seed={seed}"),
+            Err(_) => {
+                // the failure points at the first digit that does not fit
+                let prefix = if radix == 10 { 0 } else { 2 };
+                let pos = (prefix + 1..=txt.len())
+                    .find(|&end| u16::from_str_radix(&txt[prefix..end], radix).is_err())
+                    .unwrap();
+                assert_eq!(
+                    res.unwrap_err().get_error_message(),
+                    format!("[Parser error] Expected `u16 integer (overflow)` at 1:{pos}"),
+                    "Failed i={i}, y={y}, seed={seed}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of signed numbers
+            for x in 0..50 {
+                round_trip(seed, &mut rng, i, x);
+            }
+
+            // 50 iterations of overflowing numbers
+            for y in 0..50 {
+                overflow(seed, &mut rng, i, y);
+            }
+        }
+    }
+}
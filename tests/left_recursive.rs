@@ -0,0 +1,191 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod left_recursive {
+    use std::ops::Not;
+
+    use microparsec::{
+        parsers, tuple_parsers, AnyParser, CombinatorT, Context, ContextParserT, Failure,
+        IntegerParser, LazyParser, ParserRc, ParserType, RecursiveParser, StringParser,
+        StringParserT, Success, TupleParser,
+    };
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_error_message, __get_seeded_rng};
+
+    /// expr := expr "-" number | number
+    fn direct_parser() -> RecursiveParser<i64> {
+        let expr = RecursiveParser::new("expression");
+        let number = IntegerParser::new().map(|res| Ok(res.val.parse::<i64>().unwrap()));
+        let sub = TupleParser::new(tuple_parsers!(
+            expr.lazy(),
+            StringParser::new("-"),
+            number.clone()
+        ))
+        .map(|res| Ok(res.val.0 - res.val.2));
+
+        expr.bind(AnyParser::new(parsers!(sub, number)).left_recursive().rc());
+        expr
+    }
+
+    /// a := b "x" | "y", b := a "z"
+    fn indirect_parser() -> (RecursiveParser<String>, RecursiveParser<String>) {
+        let a = RecursiveParser::new("a");
+        let b = RecursiveParser::new("b");
+
+        let bx = TupleParser::new(tuple_parsers!(b.lazy(), StringParser::new("x")))
+            .map(|res| Ok(format!("{}{}", res.val.0, res.val.1)));
+        let y = StringParser::new("y").map(|res| Ok(res.val.to_string()));
+        a.bind(AnyParser::new(parsers!(bx, y)).left_recursive().rc());
+
+        let az = TupleParser::new(tuple_parsers!(a.lazy(), StringParser::new("z")))
+            .map(|res| Ok(format!("{}{}", res.val.0, res.val.1)));
+        b.bind(az.rc());
+
+        // `b` is returned as well, lazy handles do not keep a definition alive
+        (a, b)
+    }
+
+    fn direct(seed: u64, rng: &mut StdRng, parser: &RecursiveParser<i64>, i: u32, x: u32) {
+        let n = rng.gen_range(1..10);
+        let numbers: Vec<i64> = (0..n).map(|_| rng.gen_range(0..1000)).collect();
+        let txt = numbers
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>()
+            .join("-");
+
+        // a dangling operator is not consumed
+        let dangling = rng.gen_bool(0.3);
+        let input = if dangling {
+            format!("{txt}-")
+        } else {
+            txt.clone()
+        };
+
+        let res = parser.parse(&input);
+        let expected = numbers[1..].iter().fold(numbers[0], |acc, n| acc - n);
+        assert_eq!(
+            res.clone().unwrap().val,
+            expected,
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            txt.len(),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+
+        let res = parser.parse(format!("-{txt}"));
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message("integer", 0),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+    }
+
+    fn indirect(seed: u64, rng: &mut StdRng, parser: &RecursiveParser<String>, i: u32, y: u32) {
+        let n = rng.gen_range(0..10);
+        let txt = format!("y{}", "zx".repeat(n));
+
+        // a trailing `z` without `x` is not consumed
+        let res = parser.parse(format!("{txt}z"));
+        assert_eq!(
+            res.clone().unwrap().val,
+            txt,
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            txt.len(),
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+
+        let res = parser.parse(format!("z{txt}"));
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message("y", 0),
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+    }
+
+    /// Parses `@` as the value of another input that is parsed with the same expression parser
+    #[derive(Clone)]
+    struct Nested {
+        expr: LazyParser<i64>,
+        number: ParserRc<dyn ContextParserT<i64>>,
+    }
+
+    impl ContextParserT<i64> for Nested {
+        fn get_generic_error_message(&self) -> String {
+            "nested".to_string()
+        }
+
+        fn get_parser_type(&self) -> ParserType {
+            ParserType::Custom("nested".to_string())
+        }
+
+        fn parse_from_context(&self, mut ctx: Context) -> Result<Success<i64>, Failure> {
+            if ctx.txt[ctx.pos..].starts_with('@').not() {
+                return self.number.parse_from_context(ctx);
+            }
+
+            let res = self
+                .expr
+                .parse_from_context(Context::new("7-2-1", ctx.pos))?;
+            ctx.pos += 1;
+            Ok(Success::new(res.val, ctx))
+        }
+    }
+
+    #[test]
+    fn reentrant() {
+        // expr := expr "-" nested | nested
+        let expr = RecursiveParser::new("expression");
+        let nested = Nested {
+            expr: expr.lazy(),
+            number: IntegerParser::new()
+                .map(|res| Ok(res.val.parse::<i64>().unwrap()))
+                .rc(),
+        };
+        let sub = TupleParser::new(tuple_parsers!(
+            expr.lazy(),
+            StringParser::new("-"),
+            nested.clone()
+        ))
+        .map(|res| Ok(res.val.0 - res.val.2));
+        expr.bind(
+            AnyParser::new(vec![sub.rc(), nested.rc()])
+                .left_recursive()
+                .rc(),
+        );
+
+        // the nested input is parsed at the same position while the outer seed is grown
+        let res = expr.parse("@-1");
+        assert_eq!(res.clone().unwrap().val, 3);
+        assert_eq!(res.unwrap().ctx.pos, 3);
+    }
+
+    #[test]
+    fn test() {
+        // the same parsers are reused for every input
+        let direct_parser = direct_parser();
+        let (indirect_parser, _b) = indirect_parser();
+
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of direct left recursion
+            for x in 0..50 {
+                direct(seed, &mut rng, &direct_parser, i, x);
+            }
+
+            // 50 iterations of indirect left recursion
+            for y in 0..50 {
+                indirect(seed, &mut rng, &indirect_parser, i, y);
+            }
+        }
+    }
+}
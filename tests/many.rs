@@ -0,0 +1,111 @@
+mod utils;
+
+#[cfg(test)]
+mod many {
+    use microparsec::{
+        CombinatorT, Context, ContextParserT, ManyParser, ManyTillParser, NotParser,
+        OptionalParser, ParserRc, RepeatParser, SepByParser, StringParser, StringParserT, Trailing,
+    };
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_error_message, __get_rand_string, __get_seeded_rng};
+
+    fn random(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        let len = rng.gen_range(40..80);
+        let str = __get_rand_string(rng, len);
+
+        // parse repetitions of a random char at a random pos
+        let rand_pos = rng.gen_range(0..len);
+        let target = __get_rand_string(rng, 1);
+        let count = str[rand_pos..]
+            .chars()
+            .take_while(|c| target.starts_with(*c))
+            .count();
+
+        let res = ManyParser::new(ParserRc::new(StringParser::new(&target))).parse_from_context(
+            Context {
+                txt: str.as_str().into(),
+                pos: rand_pos,
+            },
+        );
+
+        if count > 0 {
+            assert_eq!(
+                res.clone().unwrap().val,
+                vec![target; count],
+                "Failed i={i}, x={x}, seed={seed}"
+            );
+            assert_eq!(
+                res.unwrap().ctx.pos,
+                rand_pos + count,
+                "Failed i={i}, x={x}, seed={seed}"
+            );
+        } else {
+            assert_eq!(
+                res.unwrap_err().get_error_message(),
+                __get_error_message(&target, rand_pos),
+                "Failed i={i}, x={x}, seed={seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 100 iterations of full random checking
+            for x in 0..100 {
+                random(seed, &mut rng, i, x);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_width() {
+        // repeating parsers that succeed without consuming input must terminate
+        let optional = StringParser::new("a").optional().rc();
+        let not = NotParser::new(",").rc();
+
+        // the repetition stops at the first success that does not advance
+        let res = StringParser::new("x").optional().many().parse("xxx");
+        assert_eq!(res.clone().unwrap().val.len(), 3);
+        assert_eq!(res.unwrap().ctx.pos, 3);
+
+        let res = ManyParser::new(not.clone()).parse("a,b");
+        assert_eq!(res.clone().unwrap().val, vec!["a"]);
+        assert_eq!(res.unwrap().ctx.pos, 1);
+
+        let res = RepeatParser::new(optional.clone(), 0..).parse("");
+        assert_eq!(res.clone().unwrap().val.len(), 0);
+        assert_eq!(res.unwrap().ctx.pos, 0);
+
+        // it is only an error if nothing or not enough was parsed before
+        let res = ManyParser::new(optional.clone()).parse("b");
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message("optional `a` to consume input", 0)
+        );
+
+        let res = RepeatParser::new(optional.clone(), 2..).parse("ab");
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message("optional `a` to consume input", 1)
+        );
+
+        let res = ManyTillParser::new(optional.clone(), ParserRc::new(StringParser::new("c")))
+            .parse("aab");
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message("optional `a` to consume input", 2)
+        );
+
+        let res = SepByParser::new(optional, OptionalParser::new(not).rc(), Trailing::Optional)
+            .parse("b");
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message("optional `a` to consume input", 1)
+        );
+    }
+}
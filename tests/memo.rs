@@ -0,0 +1,169 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod memo {
+    use std::collections::HashSet;
+
+    use microparsec::{
+        CombinatorT, Context, ContextParserT, Failure, LazyParser, MemoParser, MemoStats,
+        ParserType, RecursiveParser, StringParser, Success,
+    };
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_rand_string, __get_seeded_rng};
+
+    /// Parses at random positions of the context and checks the results against the uncached
+    /// parser, returns the positions that were parsed
+    fn lookups(
+        seed: u64,
+        rng: &mut StdRng,
+        parser: &MemoParser<String>,
+        uncached: &StringParser,
+        ctx: &Context,
+        i: u32,
+        x: u32,
+    ) -> Vec<usize> {
+        let n = rng.gen_range(1..20);
+        let positions: Vec<usize> = (0..n).map(|_| rng.gen_range(0..=ctx.txt.len())).collect();
+
+        for &pos in positions.iter() {
+            let mut ctx = ctx.clone();
+            ctx.pos = pos;
+
+            let res = parser.parse_from_context(ctx.clone());
+            match uncached.parse_from_context(ctx) {
+                Ok(expected) => {
+                    let res = res.unwrap();
+                    assert_eq!(
+                        res.val,
+                        expected.val.to_string(),
+                        "Failed i={i}, x={x}, seed={seed}"
+                    );
+                    assert_eq!(
+                        res.ctx.pos, expected.ctx.pos,
+                        "Failed i={i}, x={x}, seed={seed}"
+                    );
+                }
+                Err(expected) => assert_eq!(
+                    res.unwrap_err().get_error_message(),
+                    expected.get_error_message(),
+                    "Failed i={i}, x={x}, seed={seed}"
+                ),
+            }
+        }
+        positions
+    }
+
+    /// Returns the expected statistics after the lookups on a fresh table
+    fn expected_stats(positions: &[usize]) -> MemoStats {
+        let misses = positions.iter().collect::<HashSet<_>>().len();
+        MemoStats {
+            hits: positions.len() - misses,
+            misses,
+        }
+    }
+
+    fn memo(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        let target = __get_rand_string(rng, 1);
+        let uncached = StringParser::new(&target);
+        let parser = MemoParser::new(
+            StringParser::new(&target)
+                .map(|res| Ok(res.val.to_string()))
+                .rc(),
+        );
+
+        // every position is run once and answered from the cache afterwards
+        let n = rng.gen_range(0..10);
+        let ctx = Context::new(__get_rand_string(rng, n).as_str(), 0);
+        let positions = lookups(seed, rng, &parser, &uncached, &ctx, i, x);
+        let stats = expected_stats(&positions);
+        assert_eq!(parser.stats(), stats, "Failed i={i}, x={x}, seed={seed}");
+
+        // parsing a different input resets the table but keeps the statistics
+        let n = rng.gen_range(0..10);
+        let other = Context::new(__get_rand_string(rng, n).as_str(), 0);
+        let other_positions = lookups(seed, rng, &parser, &uncached, &other, i, x);
+        let other_stats = expected_stats(&other_positions);
+        assert_eq!(
+            parser.stats(),
+            MemoStats {
+                hits: stats.hits + other_stats.hits,
+                misses: stats.misses + other_stats.misses,
+            },
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+
+        // clearing resets both the table and the statistics
+        parser.clear();
+        assert_eq!(
+            parser.stats(),
+            MemoStats::default(),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+        let other_positions = lookups(seed, rng, &parser, &uncached, &other, i, x);
+        assert_eq!(
+            parser.stats(),
+            expected_stats(&other_positions),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+    }
+
+    /// Returns the text of its input, parsing the inner input with the memoized parser first when it
+    /// is called on any other input
+    struct Reentrant {
+        memo: LazyParser<String>,
+        inner: Context,
+    }
+
+    impl ContextParserT<String> for Reentrant {
+        fn get_generic_error_message(&self) -> String {
+            "reentrant".to_string()
+        }
+
+        fn get_parser_type(&self) -> ParserType {
+            ParserType::Custom("reentrant".to_string())
+        }
+
+        fn parse_from_context(&self, ctx: Context) -> Result<Success<String>, Failure> {
+            if ctx.txt != self.inner.txt {
+                self.memo.parse_from_context(self.inner.clone())?;
+            }
+            Ok(Success::new(ctx.txt.to_string(), ctx))
+        }
+    }
+
+    #[test]
+    fn reentrant() {
+        let recursive = RecursiveParser::new("memo");
+        let inner = Context::new("inner", 0);
+        let memo = MemoParser::new(
+            Reentrant {
+                memo: recursive.lazy(),
+                inner: inner.clone(),
+            }
+            .rc(),
+        );
+        recursive.bind(memo.clone().rc());
+
+        // the outer result must not end up in the table of the inner input
+        let res = memo.parse_from_context(Context::new("outer", 0));
+        assert_eq!(res.unwrap().val, "outer");
+        let res = memo.parse_from_context(inner);
+        assert_eq!(res.unwrap().val, "inner");
+        assert_eq!(memo.stats(), MemoStats { hits: 1, misses: 2 });
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of cached lookups
+            for x in 0..50 {
+                memo(seed, &mut rng, i, x);
+            }
+        }
+    }
+}
@@ -7,9 +7,9 @@ mod optional {
 
     use crate::utils::{__get_rand_string, __get_seeded_rng};
 
-    fn pseudo(seed: u64, mut rng: &mut StdRng, i: u32, x: u32) {
+    fn pseudo(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
         let len = rng.gen_range(40..80);
-        let str = __get_rand_string(&mut rng, len);
+        let str = __get_rand_string(rng, len);
 
         // get random substring
         let start = rng.gen_range(0..len);
@@ -34,14 +34,14 @@ mod optional {
         );
     }
 
-    fn random(seed: u64, mut rng: &mut StdRng, i: u32, y: u32) {
+    fn random(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
         let len = rng.gen_range(40..80);
-        let str = __get_rand_string(&mut rng, len);
+        let str = __get_rand_string(rng, len);
 
         // generate random string of random length and parse at random pos
         let rand_len = rng.gen_range(2..30);
         let rand_pos = rng.gen_range(0..len);
-        let substr = __get_rand_string(&mut rng, rand_len);
+        let substr = __get_rand_string(rng, rand_len);
 
         let res = OptionalParser::new(ParserRc::new(StringParser::new(&substr)))
             .parse_from_context(Context {
@@ -60,9 +60,8 @@ mod optional {
                 "Failed i={i}, y={y}, seed={seed}"
             );
         } else {
-            assert_eq!(
+            assert!(
                 res.unwrap().val.is_none(),
-                true,
                 "Failed i={i}, y={y}, seed={seed}"
             );
         }
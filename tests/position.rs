@@ -0,0 +1,87 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod position {
+    use microparsec::{Context, StringParser, StringParserT};
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_rand_string, __get_seeded_rng};
+
+    /// Returns the 1-based line and column of a byte position by scanning the text
+    fn naive_line_col(txt: &str, pos: usize) -> (usize, usize) {
+        let (mut line, mut col) = (1, 1);
+        for (i, c) in txt.char_indices() {
+            if i >= pos {
+                break;
+            }
+            match c {
+                '\n' => (line, col) = (line + 1, 1),
+                _ => col += 1,
+            }
+        }
+        (line, col)
+    }
+
+    /// Returns random lines, `f`s become line breaks and `e`s multi-byte characters
+    fn multi_line(rng: &mut StdRng, len: usize) -> String {
+        __get_rand_string(rng, len)
+            .chars()
+            .map(|c| match c {
+                'f' => "\n".to_string(),
+                'e' => "é".to_string(),
+                c => c.to_string(),
+            })
+            .collect()
+    }
+
+    fn line_col(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        let len = rng.gen_range(1..30);
+        let txt = multi_line(rng, len);
+
+        // every char boundary resolves to the same location as the naive scan
+        let ctx = Context::new(&txt, 0);
+        for pos in (0..=txt.len()).filter(|pos| txt.is_char_boundary(*pos)) {
+            let ctx = Context {
+                txt: ctx.txt.clone(),
+                pos,
+            };
+            assert_eq!(
+                ctx.line_col(),
+                naive_line_col(&txt, pos),
+                "Failed i={i}, x={x}, seed={seed}, pos={pos}"
+            );
+        }
+
+        // the input is dropped after each parse, so the next input may reuse its address
+        let res = StringParser::new("a").parse(&txt);
+        if let Err(err) = res {
+            let (line, col) = naive_line_col(&txt, 0);
+            assert!(
+                err.get_error_message()
+                    .ends_with(&format!("at {line}:{col}")),
+                "Failed i={i}, x={x}, seed={seed}"
+            );
+        }
+        let res = StringParser::new(&txt).parse(format!("{txt}!"));
+        let (line, col) = naive_line_col(&txt, txt.len());
+        assert_eq!(
+            res.unwrap().ctx.line_col(),
+            (line, col),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of random multi-line inputs
+            for x in 0..50 {
+                line_col(seed, &mut rng, i, x);
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod recursive {
+    use microparsec::{CombinatorT, RecursiveParser, StringParser, StringParserT};
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_error_message, __get_seeded_rng};
+
+    /// parentheses := "(" parentheses? ")", returns the depth of the nesting
+    fn parentheses() -> RecursiveParser<usize> {
+        let parentheses = RecursiveParser::new("parentheses");
+        parentheses.bind(
+            parentheses
+                .lazy()
+                .optional()
+                .between(StringParser::new("("), StringParser::new(")"))
+                .map(|res| Ok(res.val.unwrap_or(0) + 1))
+                .rc(),
+        );
+        parentheses
+    }
+
+    fn nesting(seed: u64, rng: &mut StdRng, parser: &RecursiveParser<usize>, i: u32, x: u32) {
+        let depth = rng.gen_range(1..20);
+        let txt = format!("{}{}", "(".repeat(depth), ")".repeat(depth));
+
+        let res = parser.parse(&txt);
+        assert_eq!(
+            res.clone().unwrap().val,
+            depth,
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            txt.len(),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+
+        // a missing closing parenthesis is reported at the end of the input
+        let res = parser.parse(&txt[..txt.len() - 1]);
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message(")", txt.len() - 1),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+    }
+
+    fn dropped(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        // only a handle of the parser is returned, the `RecursiveParser` itself is dropped
+        let lazy = parentheses().lazy().expect("parentheses");
+
+        let depth = rng.gen_range(1..20);
+        let txt = format!("{}{}", "(".repeat(depth), ")".repeat(depth));
+        let res = lazy.parse(&txt);
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message("parentheses", 0),
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+
+        let lazy = parentheses().lazy();
+        let res = lazy.parse(&txt);
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            __get_error_message("parentheses (dropped)", 0),
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Recursive parser `parentheses` was never bound")]
+    fn unbound() {
+        let parentheses: RecursiveParser<usize> = RecursiveParser::new("parentheses");
+        let _ = parentheses.lazy().parse("()");
+    }
+
+    #[test]
+    fn test() {
+        let parser = parentheses();
+
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of nested parentheses
+            for x in 0..50 {
+                nesting(seed, &mut rng, &parser, i, x);
+            }
+
+            // 50 iterations of dropped parsers
+            for y in 0..50 {
+                dropped(seed, &mut rng, i, y);
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod regex {
+    use microparsec::{RegexParser, StringParserT};
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_error_message, __get_rand_string, __get_seeded_rng};
+
+    const PATTERNS: [&str; 4] = [
+        "a+",
+        "(?x) a+ # letters",
+        "(?x)\n  a +   # one or more\n  # nothing else\n",
+        "(?i)A+",
+    ];
+
+    fn anchored(seed: u64, rng: &mut StdRng, parsers: &[RegexParser], i: u32, x: u32) {
+        let n = rng.gen_range(0..10);
+        let txt = __get_rand_string(rng, n);
+        let count = txt.chars().take_while(|&c| c == 'a').count();
+
+        for (parser, pattern) in parsers.iter().zip(PATTERNS) {
+            // a match further in the input is never found
+            let res = parser.parse(&txt);
+            if count == 0 {
+                assert_eq!(
+                    res.unwrap_err().get_error_message(),
+                    __get_error_message("letters", 0),
+                    "Failed i={i}, x={x}, seed={seed}, pattern={pattern:?}"
+                );
+            } else {
+                assert_eq!(
+                    res.clone().unwrap().val,
+                    txt[..count],
+                    "Failed i={i}, x={x}, seed={seed}, pattern={pattern:?}"
+                );
+                assert_eq!(
+                    res.unwrap().ctx.pos,
+                    count,
+                    "Failed i={i}, x={x}, seed={seed}, pattern={pattern:?}"
+                );
+            }
+        }
+    }
+
+    fn invalid(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        // patterns that are only valid if they escape the anchoring group
+        let n = rng.gen_range(0..5);
+        let txt = __get_rand_string(rng, n);
+        for pattern in [
+            format!("{txt})|({txt}"),
+            format!("{txt})"),
+            format!("(?x){txt} # comment\n)"),
+            format!("({txt}"),
+        ] {
+            assert!(
+                RegexParser::try_new(&pattern, "letters").is_err(),
+                "Failed i={i}, y={y}, seed={seed}, pattern={pattern:?}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid regex")]
+    fn panics() {
+        RegexParser::new("(a", "letters");
+    }
+
+    #[test]
+    fn test() {
+        let parsers: Vec<RegexParser> = PATTERNS
+            .iter()
+            .map(|pattern| RegexParser::try_new(pattern, "letters").unwrap())
+            .collect();
+
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of anchored matching
+            for x in 0..50 {
+                anchored(seed, &mut rng, &parsers, i, x);
+            }
+
+            // 50 iterations of invalid patterns
+            for y in 0..50 {
+                invalid(seed, &mut rng, i, y);
+            }
+        }
+    }
+}
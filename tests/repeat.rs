@@ -0,0 +1,131 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod repeat {
+    use microparsec::{
+        CombinatorT, CountParser, ManyTillParser, RepeatParser, Span, StringParser, StringParserT,
+    };
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_error_message, __get_seeded_rng};
+
+    /// Returns a `RepeatParser` of `a` with a random kind of range, and its bounds
+    fn parser(rng: &mut StdRng) -> (RepeatParser<Span>, usize, Option<usize>) {
+        let a = rng.gen_range(0..5);
+        let b = rng.gen_range(a + 1..10);
+        let parser = StringParser::new("a").rc();
+
+        match rng.gen_range(0..6) {
+            0 => (RepeatParser::new(parser, a..b), a, Some(b - 1)),
+            1 => (RepeatParser::new(parser, a..=b), a, Some(b)),
+            2 => (RepeatParser::new(parser, a..), a, None),
+            3 => (RepeatParser::new(parser, ..b), 0, Some(b - 1)),
+            4 => (RepeatParser::new(parser, ..=b), 0, Some(b)),
+            _ => (RepeatParser::new(parser, ..), 0, None),
+        }
+    }
+
+    fn repeat(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        let (parser, min, max) = parser(rng);
+        let count = rng.gen_range(0..12);
+        let txt = format!("{}b", "a".repeat(count));
+
+        let res = parser.parse(&txt);
+        if count < min {
+            let expected = match max {
+                Some(max) if max == min => format!("exactly {min} `a`"),
+                Some(max) => format!("between {min} and {max} `a`"),
+                None => format!("at least {min} `a`"),
+            };
+            assert_eq!(
+                res.unwrap_err().get_error_message(),
+                __get_error_message(&format!("{expected} (found {count})"), count),
+                "Failed i={i}, x={x}, seed={seed}"
+            );
+        } else {
+            let len = max.map_or(count, |max| count.min(max));
+            let res = res.unwrap();
+            assert_eq!(res.val.len(), len, "Failed i={i}, x={x}, seed={seed}");
+            assert_eq!(res.ctx.pos, len, "Failed i={i}, x={x}, seed={seed}");
+        }
+    }
+
+    fn count(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        let n = rng.gen_range(0..6);
+        let count = rng.gen_range(0..8);
+        let txt = format!("{}b", "a".repeat(count));
+
+        let res = CountParser::new(StringParser::new("a").rc(), n).parse(&txt);
+        if count < n {
+            assert_eq!(
+                res.unwrap_err().get_error_message(),
+                __get_error_message(&format!("exactly {n} `a` (found {count})"), count),
+                "Failed i={i}, y={y}, seed={seed}"
+            );
+        } else {
+            let res = res.unwrap();
+            assert_eq!(res.val.len(), n, "Failed i={i}, y={y}, seed={seed}");
+            assert_eq!(res.ctx.pos, n, "Failed i={i}, y={y}, seed={seed}");
+        }
+    }
+
+    fn many_till(seed: u64, rng: &mut StdRng, i: u32, z: u32) {
+        let count = rng.gen_range(0..8);
+        let end = ["b", "c", ""][rng.gen_range(0..3)];
+        let txt = format!("{}{end}", "a".repeat(count));
+
+        let parser = ManyTillParser::new(StringParser::new("a").rc(), StringParser::new("b").rc());
+        let res = parser.parse(&txt);
+        match end {
+            "b" => {
+                let res = res.unwrap();
+                assert_eq!(res.val.0.len(), count, "Failed i={i}, z={z}, seed={seed}");
+                assert_eq!(res.val.1, "b", "Failed i={i}, z={z}, seed={seed}");
+                assert_eq!(res.ctx.pos, count + 1, "Failed i={i}, z={z}, seed={seed}");
+            }
+            // neither the end nor another item matched at the same position
+            _ => assert_eq!(
+                res.unwrap_err().get_error_message(),
+                format!("[Parser error] Expected `b` or `a` at 1:{}", count + 1),
+                "Failed i={i}, z={z}, seed={seed}"
+            ),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Range must not be empty")]
+    fn empty_exclusive() {
+        RepeatParser::new(StringParser::new("a").rc(), ..0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Range must not be empty")]
+    fn empty_inclusive() {
+        #[allow(clippy::reversed_empty_ranges)]
+        RepeatParser::new(StringParser::new("a").rc(), 3..=2);
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of bounded repetitions
+            for x in 0..50 {
+                repeat(seed, &mut rng, i, x);
+            }
+
+            // 50 iterations of counted repetitions
+            for y in 0..50 {
+                count(seed, &mut rng, i, y);
+            }
+
+            // 50 iterations of repetitions until an end
+            for z in 0..50 {
+                many_till(seed, &mut rng, i, z);
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod sep_by {
+    use microparsec::{
+        CombinatorT, OneOfParser, SepBy1Parser, SepByParser, StringParser, StringParserT, Trailing,
+    };
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::{__get_error_message, __get_rand_string, __get_seeded_rng};
+
+    const TRAILINGS: [Trailing; 3] = [Trailing::Forbidden, Trailing::Optional, Trailing::Required];
+
+    /// Returns the expected values and position, or the expected error and its position
+    fn expected(
+        items: &str,
+        trailing_separator: bool,
+        trailing: Trailing,
+        min: usize,
+    ) -> Result<(Vec<char>, usize), (&'static str, usize)> {
+        let values: Vec<char> = items.chars().collect();
+        let len = match values.len() {
+            0 => 0,
+            n => 2 * n - 1,
+        };
+
+        match (values.len(), trailing_separator, trailing) {
+            // nothing parsed yet, a separator is never consumed on its own
+            (0, _, _) if min == 0 => Ok((values, 0)),
+            (0, _, _) => Err(("one of [abc]", 0)),
+            // a separator must be followed by another value
+            (_, true, Trailing::Forbidden) => Err(("one of [abc]", len + 1)),
+            (_, true, _) => Ok((values, len + 1)),
+            // every value must be followed by a separator
+            (_, false, Trailing::Required) => Err((",", len)),
+            (_, false, _) => Ok((values, len)),
+        }
+    }
+
+    fn separated(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        let n = rng.gen_range(0..6);
+        let items: String = __get_rand_string(rng, n)
+            .chars()
+            .map(|c| match c {
+                'd' | 'e' | 'f' => 'a',
+                c => c,
+            })
+            .collect();
+        let trailing_separator = rng.gen_bool(0.5);
+
+        // the list is followed by something that is neither a value nor a separator
+        let mut txt = items
+            .chars()
+            .map(String::from)
+            .collect::<Vec<String>>()
+            .join(",");
+        if trailing_separator {
+            txt.push(',');
+        }
+        txt.push('!');
+
+        let value = OneOfParser::new("abc").rc();
+        let separator = StringParser::new(",").rc();
+        for trailing in TRAILINGS {
+            for min in [0, 1] {
+                let res = match min {
+                    0 => SepByParser::new(value.clone(), separator.clone(), trailing).parse(&txt),
+                    _ => SepBy1Parser::new(value.clone(), separator.clone(), trailing).parse(&txt),
+                };
+
+                match expected(&items, trailing_separator, trailing, min) {
+                    Ok((values, pos)) => {
+                        let res = res.unwrap();
+                        assert_eq!(
+                            res.val, values,
+                            "Failed i={i}, x={x}, seed={seed}, trailing={trailing:?}, min={min}"
+                        );
+                        assert_eq!(
+                            res.ctx.pos, pos,
+                            "Failed i={i}, x={x}, seed={seed}, trailing={trailing:?}, min={min}"
+                        );
+                    }
+                    Err((exp, pos)) => assert_eq!(
+                        res.unwrap_err().get_error_message(),
+                        __get_error_message(exp, pos),
+                        "Failed i={i}, x={x}, seed={seed}, trailing={trailing:?}, min={min}"
+                    ),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of separated lists
+            for x in 0..50 {
+                separated(seed, &mut rng, i, x);
+            }
+        }
+    }
+}
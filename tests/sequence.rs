@@ -4,14 +4,14 @@ mod utils;
 mod sequence {
     use std::ops::Not;
 
-    use microparsec::{Context, ContextParserT, ParserRc, SequenceParser, StringParser};
+    use microparsec::{Context, ContextParserT, ParserRc, SequenceParser, Span, StringParser};
     use rand::{rngs::StdRng, Rng};
 
     use crate::utils::{__get_error_message, __get_rand_string, __get_seeded_rng};
 
-    fn pseudo(seed: u64, mut rng: &mut StdRng, i: u32, x: u32) {
+    fn pseudo(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
         let len = rng.gen_range(40..80);
-        let str = __get_rand_string(&mut rng, len);
+        let str = __get_rand_string(rng, len);
 
         let sequence_len = rng.gen_range(1..7);
 
@@ -24,7 +24,7 @@ mod sequence {
         let mut pieces = Vec::new();
 
         // cut up substring into pieces
-        let mut sequence: Vec<ParserRc<dyn ContextParserT<String>>> = Vec::new();
+        let mut sequence: Vec<ParserRc<dyn ContextParserT<Span>>> = Vec::new();
         for j in 0..sequence_len - 1 {
             let snippet = &substr[j * piece_len..(j + 1) * piece_len];
             sequence.push(ParserRc::new(StringParser::new(snippet)));
@@ -51,9 +51,9 @@ mod sequence {
         );
     }
 
-    fn pseudo_randomized(seed: u64, mut rng: &mut StdRng, i: u32, y: u32) {
+    fn pseudo_randomized(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
         let len = rng.gen_range(40..80);
-        let str = __get_rand_string(&mut rng, len);
+        let str = __get_rand_string(rng, len);
 
         let sequence_len = rng.gen_range(1..7);
 
@@ -66,7 +66,7 @@ mod sequence {
         let mut pieces = Vec::new();
 
         // cut up substring into pieces
-        let mut sequence: Vec<ParserRc<dyn ContextParserT<String>>> = Vec::new();
+        let mut sequence: Vec<ParserRc<dyn ContextParserT<Span>>> = Vec::new();
         for j in 0..sequence_len - 1 {
             let snippet = &substr[j * piece_len..(j + 1) * piece_len];
             sequence.push(ParserRc::new(StringParser::new(snippet)));
@@ -79,7 +79,7 @@ mod sequence {
 
         // pick one index and change the string there randomly
         let change = rng.gen_range(0..sequence_len);
-        let replacement = __get_rand_string(&mut rng, pieces[change].len());
+        let replacement = __get_rand_string(rng, pieces[change].len());
         sequence[change] = ParserRc::new(StringParser::new(&replacement));
         pieces[change] = replacement.as_str();
 
@@ -90,19 +90,19 @@ mod sequence {
 
         let mut offset = 0;
         let mut found_invalid = false;
-        for j in 0..sequence_len {
+        for (j, piece) in pieces.iter().enumerate() {
             // if the current is invalid, check that error is correct
-            if str[start + offset..].starts_with(pieces[j]).not() {
+            if str[start + offset..].starts_with(piece).not() {
                 assert_eq!(
                     res.clone().unwrap_err().get_error_message(),
-                    __get_error_message(pieces[j], start + offset),
+                    __get_error_message(piece, start + offset),
                     "Failed i={i}, y={y}, j={j}, seed={seed}"
                 );
                 found_invalid = true;
                 break;
             }
 
-            offset += pieces[j].len();
+            offset += piece.len();
         }
 
         if found_invalid.not() {
@@ -119,21 +119,21 @@ mod sequence {
         }
     }
 
-    fn random(seed: u64, mut rng: &mut StdRng, i: u32, z: u32) {
+    fn random(seed: u64, rng: &mut StdRng, i: u32, z: u32) {
         let len = rng.gen_range(40..80);
-        let str = __get_rand_string(&mut rng, len);
+        let str = __get_rand_string(rng, len);
 
         // generate random string of random length and parse at random pos
         let sequence_len = rng.gen_range(1..7);
         let rand_len = rng.gen_range(sequence_len..30);
         let rand_pos = rng.gen_range(0..len);
-        let substr = __get_rand_string(&mut rng, rand_len);
+        let substr = __get_rand_string(rng, rand_len);
 
         let piece_len = substr.len() / sequence_len;
         let mut pieces = Vec::new();
 
         // cut up substring into pieces
-        let mut sequence: Vec<ParserRc<dyn ContextParserT<String>>> = Vec::new();
+        let mut sequence: Vec<ParserRc<dyn ContextParserT<Span>>> = Vec::new();
         for j in 0..sequence_len - 1 {
             let snippet = &substr[j * piece_len..(j + 1) * piece_len];
             sequence.push(ParserRc::new(StringParser::new(snippet)));
@@ -151,19 +151,19 @@ mod sequence {
 
         let mut offset = 0;
         let mut found_invalid = false;
-        for j in 0..sequence_len {
+        for (j, piece) in pieces.iter().enumerate() {
             // if the current is invalid, check that error is correct
-            if str[rand_pos + offset..].starts_with(pieces[j]).not() {
+            if str[rand_pos + offset..].starts_with(piece).not() {
                 assert_eq!(
                     res.clone().unwrap_err().get_error_message(),
-                    __get_error_message(pieces[j], rand_pos + offset),
+                    __get_error_message(piece, rand_pos + offset),
                     "Failed i={i}, z={z}, j={j}, seed={seed}"
                 );
                 found_invalid = true;
                 break;
             }
 
-            offset += pieces[j].len();
+            offset += piece.len();
         }
 
         if found_invalid.not() {
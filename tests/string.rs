@@ -2,14 +2,16 @@ mod utils;
 
 #[cfg(test)]
 mod string {
-    use microparsec::{Context, ContextParserT, StringParser};
+    use std::ops::Not;
+
+    use microparsec::{Context, ContextParserT, Matching, StringParser};
     use rand::{rngs::StdRng, Rng};
 
     use crate::utils::{__get_error_message, __get_rand_string, __get_seeded_rng};
 
-    fn pseudo(seed: u64, mut rng: &mut StdRng, i: u32, x: u32) {
+    fn pseudo(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
         let len = rng.gen_range(40..80);
-        let str = __get_rand_string(&mut rng, len);
+        let str = __get_rand_string(rng, len);
 
         // get random substring
         let start = rng.gen_range(0..len);
@@ -32,14 +34,14 @@ mod string {
         );
     }
 
-    fn random(seed: u64, mut rng: &mut StdRng, i: u32, y: u32) {
+    fn random(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
         let len = rng.gen_range(40..80);
-        let str = __get_rand_string(&mut rng, len);
+        let str = __get_rand_string(rng, len);
 
         // generate random string of random length and parse at random pos
         let rand_len = rng.gen_range(2..30);
         let rand_pos = rng.gen_range(0..len);
-        let substr = __get_rand_string(&mut rng, rand_len);
+        let substr = __get_rand_string(rng, rand_len);
 
         let res = StringParser::new(&substr).parse_from_context(Context {
             txt: str.as_str().into(),
@@ -66,6 +68,79 @@ mod string {
         }
     }
 
+    fn matching(seed: u64, rng: &mut StdRng, i: u32, z: u32) {
+        let len = rng.gen_range(2..30);
+        let target = __get_rand_string(rng, len);
+
+        // randomly change the case of the letters, the `e`s become composed or decomposed accents
+        let input: String = target
+            .chars()
+            .map(|c| match (c, rng.gen_bool(0.5)) {
+                ('e', true) => "e\u{301}".to_string(),
+                ('e', false) => "\u{e9}".to_string(),
+                (c, true) => c.to_ascii_uppercase().to_string(),
+                (c, false) => c.to_string(),
+            })
+            .collect();
+        let target = target.replace('e', "\u{e9}");
+        let txt = format!("{input} rest");
+
+        let res = StringParser::with_matching(&target, Matching::Nfc).parse_from_context(Context {
+            txt: txt.as_str().into(),
+            pos: 0,
+        });
+        let expected = input.chars().all(|c| c.is_ascii_uppercase().not());
+        assert_eq!(res.is_ok(), expected, "Failed i={i}, z={z}, seed={seed}");
+
+        let upper = target.to_uppercase();
+        let res = StringParser::with_matching(&target, Matching::IgnoreCase).parse_from_context(
+            Context {
+                txt: format!("{upper} rest").as_str().into(),
+                pos: 0,
+            },
+        );
+        assert_eq!(
+            res.clone().unwrap().val,
+            upper,
+            "Failed i={i}, z={z}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            upper.len(),
+            "Failed i={i}, z={z}, seed={seed}"
+        );
+
+        let txt = target.to_ascii_uppercase();
+        let res = StringParser::with_matching(&target, Matching::IgnoreAsciiCase)
+            .parse_from_context(Context {
+                txt: txt.as_str().into(),
+                pos: 0,
+            });
+        assert_eq!(res.unwrap().val, txt, "Failed i={i}, z={z}, seed={seed}");
+    }
+
+    #[test]
+    fn case_folding() {
+        // (target, input, matched length)
+        let cases = [
+            ("straße", "STRASSE", Some(7)),
+            ("STRASSE", "straße", Some(7)),
+            ("σοφός", "ΣΟΦΌΣ", Some(10)),
+            ("ﬁle", "FILE", Some(4)),
+            ("straße", "STRAS", None),
+        ];
+
+        for (target, input, len) in cases {
+            let res = StringParser::with_matching(target, Matching::IgnoreCase).parse_from_context(
+                Context {
+                    txt: input.into(),
+                    pos: 0,
+                },
+            );
+            assert_eq!(res.ok().map(|res| res.ctx.pos), len, "Failed {target}");
+        }
+    }
+
     #[test]
     fn test() {
         // 1000 iterations of random testing
@@ -81,6 +156,11 @@ mod string {
             for y in 0..100 {
                 random(seed, &mut rng, i, y);
             }
+
+            // 50 iterations of case-insensitive and normalized matching
+            for z in 0..50 {
+                matching(seed, &mut rng, i, z);
+            }
         }
     }
 }
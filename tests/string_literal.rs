@@ -0,0 +1,102 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod string_literal {
+    use microparsec::{StringLiteralParser, StringParserT};
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+
+    use crate::utils::__get_seeded_rng;
+
+    const CHARS: [char; 12] = [
+        'a', 'b', ' ', '"', '\'', '\\', '\n', '\t', 'é', '😀', '🦀', '#',
+    ];
+
+    /// Escapes a character in one of the ways the parser accepts
+    fn escape(rng: &mut StdRng, c: char) -> String {
+        let mut units = [0; 2];
+        match rng.gen_range(0..3) {
+            0 => format!("\\u{{{:x}}}", c as u32),
+            1 => c
+                .encode_utf16(&mut units)
+                .iter()
+                .map(|unit| format!("\\u{unit:04X}"))
+                .collect(),
+            _ => match c {
+                '\n' => "\\n".to_string(),
+                '\t' => "\\t".to_string(),
+                '"' | '\'' | '\\' => format!("\\{c}"),
+                c => c.to_string(),
+            },
+        }
+    }
+
+    fn escaped(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        let n = rng.gen_range(0..20);
+        let val: String = (0..n).map(|_| *CHARS.choose(rng).unwrap()).collect();
+
+        let body: String = val.chars().map(|c| escape(rng, c)).collect();
+        let txt = format!("\"{body}\" rest");
+
+        let res = StringLiteralParser::new("\"", '\\').parse(&txt);
+        assert_eq!(
+            res.clone().unwrap().val,
+            val,
+            "Failed i={i}, x={x}, seed={seed}, txt={txt:?}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            body.len() + 2,
+            "Failed i={i}, x={x}, seed={seed}, txt={txt:?}"
+        );
+
+        // an invalid escape somewhere in the body fails at its escape character
+        let prefix = format!("\"{body}");
+        let txt = format!("{prefix}\\q\"");
+        let res = StringLiteralParser::new("\"", '\\').parse(&txt);
+        assert_eq!(
+            res.unwrap_err().ctx.pos,
+            prefix.len(),
+            "Failed i={i}, x={x}, seed={seed}, txt={txt:?}"
+        );
+    }
+
+    fn raw(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        let n = rng.gen_range(0..20);
+        let val: String = (0..n).map(|_| *CHARS.choose(rng).unwrap()).collect();
+
+        // enough hashes so the value cannot close the literal early
+        let hashes = "#".repeat(val.matches('#').count() + 1);
+        let txt = format!("r{hashes}\"{val}\"{hashes}");
+
+        let res = StringLiteralParser::raw("\"").parse(&txt);
+        assert_eq!(
+            res.clone().unwrap().val,
+            val,
+            "Failed i={i}, y={y}, seed={seed}, txt={txt:?}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            txt.len(),
+            "Failed i={i}, y={y}, seed={seed}, txt={txt:?}"
+        );
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 50 iterations of escaped strings
+            for x in 0..50 {
+                escaped(seed, &mut rng, i, x);
+            }
+
+            // 50 iterations of raw strings
+            for y in 0..50 {
+                raw(seed, &mut rng, i, y);
+            }
+        }
+    }
+}
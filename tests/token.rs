@@ -0,0 +1,169 @@
+#[allow(dead_code)]
+mod utils;
+
+#[cfg(test)]
+mod token {
+    use std::ops::Range;
+
+    use microparsec::{
+        Context, ContextParserT, ParserRc, RepeatParser, SliceParserT, TokenParser, TokenT,
+    };
+    use rand::{rngs::StdRng, Rng};
+
+    use crate::utils::__get_seeded_rng;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Token {
+        A,
+        B,
+        C,
+    }
+
+    impl TokenT for Token {
+        type Kind = Token;
+
+        fn kind(&self) -> Token {
+            self.clone()
+        }
+    }
+
+    /// Token of a lexer that carries its source span next to its kind
+    #[derive(Debug, Clone, PartialEq)]
+    struct Spanned {
+        kind: Token,
+        span: Range<usize>,
+    }
+
+    impl TokenT for Spanned {
+        type Kind = Token;
+
+        fn kind(&self) -> Token {
+            self.kind.clone()
+        }
+
+        fn span(&self) -> Option<Range<usize>> {
+            Some(self.span.clone())
+        }
+    }
+
+    fn random_token(rng: &mut StdRng) -> Token {
+        match rng.gen_range(0..3) {
+            0 => Token::A,
+            1 => Token::B,
+            _ => Token::C,
+        }
+    }
+
+    fn random(seed: u64, rng: &mut StdRng, i: u32, x: u32) {
+        let len = rng.gen_range(40..80);
+        let tokens = (0..len).map(|_| random_token(rng)).collect::<Vec<Token>>();
+
+        // parse repetitions of a random token at a random pos
+        let rand_pos = rng.gen_range(0..len);
+        let target = random_token(rng);
+        let count = tokens[rand_pos..]
+            .iter()
+            .take_while(|t| **t == target)
+            .count();
+
+        let parser = TokenParser::new(target.clone());
+        let res =
+            RepeatParser::new(ParserRc::new(parser.clone()), 0..).parse_from_context(Context {
+                txt: tokens.as_slice().into(),
+                pos: rand_pos,
+            });
+        assert_eq!(
+            res.clone().unwrap().val,
+            vec![target.clone(); count],
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            rand_pos + count,
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+
+        // the first token that is not the target is reported by its index
+        let res = parser.parse_from_context(Context {
+            txt: tokens.as_slice().into(),
+            pos: rand_pos + count,
+        });
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            format!(
+                "[Parser error] Expected `{target:?}` at token {}",
+                rand_pos + count
+            ),
+            "Failed i={i}, x={x}, seed={seed}"
+        );
+    }
+
+    fn spanned(seed: u64, rng: &mut StdRng, i: u32, y: u32) {
+        // tokens of random lengths separated by single spaces
+        let len = rng.gen_range(1..20);
+        let mut start = 0;
+        let tokens = (0..len)
+            .map(|_| {
+                let end = start + rng.gen_range(1..5);
+                let token = Spanned {
+                    kind: random_token(rng),
+                    span: start..end,
+                };
+                start = end + 1;
+                token
+            })
+            .collect::<Vec<Spanned>>();
+
+        // tokens are matched by their kind, regardless of their span
+        let target = random_token(rng);
+        let count = tokens.iter().take_while(|t| t.kind == target).count();
+        let parser = TokenParser::<Spanned>::new(target.clone());
+        let res = RepeatParser::new(ParserRc::new(parser.clone()), 0..).parse(&tokens);
+        assert_eq!(
+            res.clone().unwrap().val,
+            tokens[..count],
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+        assert_eq!(
+            res.unwrap().ctx.pos,
+            count,
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+
+        // the first token of another kind is reported by its index and span
+        let res = parser.parse_from_context(Context {
+            txt: tokens.as_slice().into(),
+            pos: count,
+        });
+        let expected = match tokens.get(count) {
+            Some(token) => format!(
+                "[Parser error] Expected `{target:?}` at token {count} ({}..{})",
+                token.span.start, token.span.end
+            ),
+            None => format!("[Parser error] Expected `{target:?}` at token {count}"),
+        };
+        assert_eq!(
+            res.unwrap_err().get_error_message(),
+            expected,
+            "Failed i={i}, y={y}, seed={seed}"
+        );
+    }
+
+    #[test]
+    fn test() {
+        // 1000 iterations of random testing
+        for i in 0..1_000 {
+            let (seed, mut rng) = __get_seeded_rng();
+
+            // 100 iterations of full random checking
+            for x in 0..100 {
+                random(seed, &mut rng, i, x);
+            }
+
+            // 50 iterations of tokens with spans
+            for y in 0..50 {
+                spanned(seed, &mut rng, i, y);
+            }
+        }
+    }
+}
@@ -166,7 +166,7 @@ fn exact_test() {
     let res = ExactParser::new(hello_world.clone(), Pos::EOI).parse("Hello World.");
     assert_eq!(
         res.unwrap_err().get_error_message(),
-        __test_get_error_message("parsing to EOI", 0)
+        __test_get_error_message("end of input", 11)
     );
 
     let res = ExactParser::new(hello_world, Pos::Chars(12)).parse("Hello World.");
@@ -377,4 +377,3 @@ fn p_type_stack_test() {
     );
 }
 */
-